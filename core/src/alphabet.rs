@@ -0,0 +1,179 @@
+use crate::error::Error;
+
+/**
+the letters the words of a word list are spelled with, lowercase and in the order
+the non interactive keyboard is printed in.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    letters: Vec<char>,
+}
+
+// the alphabets that can be chosen by name
+const PRESETS: [(&str, &str); 4] = [
+    ("english", "abcdefghijklmnopqrstuvwxyz"),
+    ("spanish", "abcdefghijklmnñopqrstuvwxyz"),
+    ("german", "abcdefghijklmnopqrstuvwxyzäöüß"),
+    ("portuguese", "abcdefghijklmnopqrstuvwxyzáâãàçéêíóôõú"),
+];
+
+impl Alphabet {
+    /**
+    function: to get the alphabet of the English words, the one of the built-in word lists
+    input: None
+    output: the 26 letters from a to z
+    */
+    pub fn english() -> Alphabet {
+        Alphabet {
+            letters: PRESETS[0].1.chars().collect(),
+        }
+    }
+
+    /**
+    function: to read an alphabet from the "--alphabet" argument
+    input: text: the name of a preset ("english", "spanish", "german", "portuguese"),
+            or the letters of the alphabet in order
+    output: the alphabet, a word list error on the alphabet if a letter is not alphabetic or appears twice
+    */
+    pub fn new(text: &str) -> Result<Alphabet, Error> {
+        let text = match PRESETS.iter().find(|(name, _)| *name == text) {
+            Some((_, letters)) => letters,
+            None => text,
+        };
+        let mut letters: Vec<char> = vec![];
+        for letter in lower(text).chars() {
+            if !letter.is_alphabetic() || letters.contains(&letter) {
+                return Err(Error::WordList {
+                    list: String::from("alphabet"),
+                    path: None,
+                    line: None,
+                    word: Some(letter.to_string()),
+                    reason: match letter.is_alphabetic() {
+                        true => String::from("appears twice"),
+                        false => String::from("is not a letter"),
+                    },
+                });
+            }
+            letters.push(letter);
+        }
+        if letters.is_empty() {
+            return Err(Error::word_list(
+                "alphabet",
+                &None,
+                String::from("has no letter"),
+            ));
+        }
+        Ok(Alphabet { letters })
+    }
+
+    /**
+    function: to build the alphabet of a word list not given one, the English letters
+            followed by every other letter the words are spelled with
+    input: words: the lowercase words of the lists
+    output: the alphabet
+    */
+    pub fn spelling(words: &[String]) -> Alphabet {
+        let mut alphabet = Alphabet::english();
+        let mut extra: Vec<char> = words
+            .iter()
+            .flat_map(|word| word.chars())
+            .filter(|letter| letter.is_alphabetic() && !alphabet.contains(*letter))
+            .collect();
+        extra.sort_unstable();
+        extra.dedup();
+        alphabet.letters.extend(extra);
+        alphabet
+    }
+
+    /**
+    function: to get the letters of the alphabet
+    input: None
+    output: the lowercase letters in order
+    */
+    pub fn letters(&self) -> &[char] {
+        &self.letters
+    }
+
+    /**
+    function: to count the letters of the alphabet
+    input: None
+    output: the number of letters
+    */
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /**
+    function: to check whether the alphabet has no letter
+    input: None
+    output: always false, an alphabet is never built empty
+    */
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+
+    /**
+    function: to get the order of a letter in the alphabet
+    input: letter: the letter in any case
+    output: the order from 0, None for a letter out of the alphabet
+    */
+    pub fn index(&self, letter: char) -> Option<usize> {
+        let letter = lower_letter(letter);
+        self.letters.iter().position(|other| *other == letter)
+    }
+
+    /**
+    function: to check whether a letter is in the alphabet
+    input: letter: the letter in any case
+    output: true if it is
+    */
+    pub fn contains(&self, letter: char) -> bool {
+        self.index(letter).is_some()
+    }
+}
+
+/**
+function: to fold a letter to uppercase, keeping it as it is if its uppercase is
+            not a single letter (like "ß", whose uppercase is "SS")
+input: letter: the letter
+output: the uppercase letter
+*/
+pub fn upper_letter(letter: char) -> char {
+    let mut upper = letter.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(single), None) => single,
+        _ => letter,
+    }
+}
+
+/**
+function: to fold a letter to lowercase, keeping it as it is if its lowercase is
+            not a single letter
+input: letter: the letter
+output: the lowercase letter
+*/
+pub fn lower_letter(letter: char) -> char {
+    let mut lower = letter.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(single), None) => single,
+        _ => letter,
+    }
+}
+
+/**
+function: to fold a word to uppercase letter by letter, so it keeps its length
+input: word: the word
+output: the uppercase word
+*/
+pub fn upper(word: &str) -> String {
+    word.chars().map(upper_letter).collect()
+}
+
+/**
+function: to fold a word to lowercase letter by letter, so it keeps its length
+input: word: the word
+output: the lowercase word
+*/
+pub fn lower(word: &str) -> String {
+    word.chars().map(lower_letter).collect()
+}
@@ -0,0 +1,205 @@
+use crate::{alphabet::lower, common::Difficulty, dictionary::Dictionary, error::Error};
+
+/**
+a game set by one player for the others: the answers, the word length, the
+difficulty and the word lists it was made with, written as an opaque code.
+the code is scrambled so the answers can't be read off it, and carries a
+checksum so a mistyped or altered code is told apart from a real one.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    answers: Vec<String>, // lowercase, one for each board
+    length: usize,
+    level: Difficulty,
+    lists: u32, // the identity of the word lists
+}
+
+// the layout of the code, raised whenever it changes
+const VERSION: u8 = 1;
+
+// the digits of the code, Crockford's base 32 without the letters mistaken for digits
+const DIGITS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/**
+function: the 32-bit FNV-1a hash
+input: bytes: the bytes to be hashed
+output: the hash
+*/
+pub(crate) fn fnv(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for byte in bytes {
+        hash ^= *byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    hash
+}
+
+/**
+function: to scramble or unscramble the bytes of a code, xor-ing them with a xorshift stream
+input: bytes: the bytes
+        seed: the seed of the stream, the checksum of the code
+output: None
+*/
+fn scramble(bytes: &mut [u8], seed: u32) {
+    let mut state = seed | 1; // never 0, or the stream stays 0
+    for byte in bytes.iter_mut() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        *byte ^= state as u8;
+    }
+}
+
+impl Challenge {
+    /**
+    function: to set a challenge
+    input: answers: the answer of each board, in any case
+            level: the difficulty of the game
+            dict: the word lists the answers are from
+    output: the challenge
+    */
+    pub fn new(answers: &[String], level: Difficulty, dict: &Dictionary) -> Challenge {
+        Challenge {
+            answers: answers.iter().map(|ans| lower(ans)).collect(),
+            length: answers[0].chars().count(),
+            level,
+            lists: dict.identity(),
+        }
+    }
+
+    /**
+    function: to write the challenge as a code
+    input: None
+    output: the code, in the digits 0-9 and A-Z
+    */
+    pub fn code(&self) -> String {
+        let mut bytes = vec![
+            VERSION,
+            self.level as u8,
+            self.length as u8,
+            self.answers.len() as u8,
+        ];
+        bytes.extend(self.lists.to_be_bytes());
+        bytes.extend(self.answers.join(",").bytes());
+        let checksum = fnv(&bytes);
+        scramble(&mut bytes, checksum);
+        let mut code = checksum.to_be_bytes().to_vec();
+        code.extend(bytes);
+        // 5 bits for each digit
+        let mut text = String::new();
+        let (mut buffer, mut bits) = (0u32, 0);
+        for byte in code {
+            buffer = (buffer << 8 | byte as u32) & 0xfff;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                text.push(DIGITS[(buffer >> bits & 31) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            text.push(DIGITS[(buffer << (5 - bits) & 31) as usize] as char);
+        }
+        text
+    }
+
+    /**
+    function: to read a challenge from its code
+    input: code: the code, in any case
+    output: the challenge, a challenge error if the code is not one or was altered
+    */
+    pub fn decode(code: &str) -> Result<Challenge, Error> {
+        let invalid = || Error::Challenge(format!("{} is not a challenge code", code.trim()));
+        let mut bytes: Vec<u8> = vec![];
+        let (mut buffer, mut bits) = (0u32, 0);
+        for digit in code.trim().chars() {
+            let digit = match digit.to_ascii_uppercase() {
+                'O' => '0',
+                'I' | 'L' => '1', // read as they are mistaken for
+                digit => digit,
+            };
+            let value = match DIGITS.iter().position(|other| *other as char == digit) {
+                Some(value) => value as u32,
+                None => return Err(invalid()),
+            };
+            buffer = (buffer << 5 | value) & 0xfff;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+            }
+        }
+        if bytes.len() < 12 {
+            return Err(invalid());
+        }
+        let checksum = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let mut bytes = bytes.split_off(4);
+        scramble(&mut bytes, checksum);
+        if fnv(&bytes) != checksum {
+            return Err(Error::Challenge(String::from("the code was altered")));
+        }
+        if bytes[0] != VERSION {
+            return Err(Error::Challenge(String::from("made by another version")));
+        }
+        let level = match bytes[1] {
+            0 => Difficulty::Normal,
+            1 => Difficulty::Hard,
+            2 => Difficulty::Strict,
+            _ => return Err(invalid()),
+        };
+        let answers: Vec<String> = match String::from_utf8(bytes[8..].to_vec()) {
+            Ok(answers) => answers.split(',').map(String::from).collect(),
+            Err(_) => return Err(invalid()),
+        };
+        let length = bytes[2] as usize;
+        if answers.len() != bytes[3] as usize
+            || answers.iter().any(|ans| ans.chars().count() != length)
+        {
+            return Err(invalid());
+        }
+        Ok(Challenge {
+            answers,
+            length,
+            level,
+            lists: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        })
+    }
+
+    /**
+    function: to get the answers of the challenge
+    input: None
+    output: the lowercase answer of each board
+    */
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /**
+    function: to get the word length of the challenge
+    input: None
+    output: the word length
+    */
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /**
+    function: to get the difficulty of the challenge
+    input: None
+    output: the difficulty
+    */
+    pub fn level(&self) -> Difficulty {
+        self.level
+    }
+
+    /**
+    function: to check that the challenge is played with the word lists it was made with
+    input: dict: the word lists of the game
+    output: Ok if they are the same, a challenge error if they are not
+    */
+    pub fn check(&self, dict: &Dictionary) -> Result<(), Error> {
+        if self.lists != dict.identity() {
+            return Err(Error::Challenge(String::from("made with other word lists")));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,2038 @@
+use {
+    crate::{
+        alphabet::{lower, upper, Alphabet},
+        builtin_words::{ACCEPTABLE, FINAL},
+        challenge::Challenge,
+        dictionary::Dictionary,
+        error::Error,
+        feedback::{Feedback, Tile, MAX_TILES},
+        layout::Layout,
+        locale::Lang,
+        settings::{conflicts, discover, ConfigFile},
+    },
+    chrono::{Local, NaiveDate},
+    clap::{
+        builder::RangedU64ValueParser, parser::ValueSource, Arg, ArgAction, ArgMatches, Command,
+    },
+    rand::{rngs::StdRng, seq::SliceRandom, SeedableRng},
+    serde_derive::{Deserialize, Serialize},
+    std::{
+        cmp::Ordering,
+        io,
+        io::{Read, Write},
+        path::Path,
+        str::FromStr,
+        time::{Duration, Instant},
+    },
+};
+
+// the struct of how the json file stores a round of game
+#[derive(Serialize, Deserialize)]
+pub struct Round {
+    pub answer: Option<String>,
+    pub guesses: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_guesses: Option<usize>, // of a single board as in Unfinished, 0 for unlimited, not saved when it is the default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answers: Option<Vec<String>>, // the answer of every board, only saved for several boards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solved: Option<Vec<Option<usize>>>, // the guess every board was solved at, null for failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<f64>, // the seconds the round took, missing in the rounds saved before it was kept
+    #[serde(flatten)]
+    pub details: Details, // how the round was played, missing in the version 1 files
+}
+
+// the version of the state file written, version 1 is the format without a version
+pub const STATE_VERSION: u32 = 2;
+
+//the struct of the json file's content
+#[derive(Serialize, Deserialize)]
+pub struct Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    pub total_rounds: Option<usize>,
+    pub games: Option<Vec<Round>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfinished: Option<Unfinished>, // the round left in the middle, offered to be continued
+}
+
+// the struct of how the json file stores a round left in the middle
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Unfinished {
+    pub answers: Vec<String>, // the answer of every board, none in the absurdle mode
+    pub guesses: Vec<String>, // the valid guesses made so far
+    pub max_guesses: usize,   // of a single board as given by --max-guesses, 0 for unlimited
+    #[serde(flatten)]
+    pub details: Details,
+}
+
+/**
+the way the answers of a round were set
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+    Input,      // the answers read before the round
+    Word,       // the answers given by --word
+    Random,     // picked from the seeded shuffled final set
+    Daily,      // the daily puzzle, of today or from the archive
+    Absurdle,   // dodged until the round ends
+    Challenge,  // set by another player's code
+    Speedrun,   // a fixed number of seeded rounds against the clock
+    TimeAttack, // as many seeded rounds as the minutes allow
+}
+
+/**
+how a round was played, kept in the state file along with its guesses.
+every fact is left out of the file when it is unknown, as in the files of version 1.
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Details {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>, // only for the answers picked from the shuffled final set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<usize>, // where the first answer is in the shuffled final set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>, // the date of the daily puzzle played in the round
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_lists: Option<String>, // the identity of the word lists, in hex
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started: Option<String>, // RFC 3339, missing in the rounds saved before it was kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid: Option<Vec<String>>, // the guesses turned down, in the order they were made
+}
+
+//the struct for the config file, its keys are checked one by one in settings
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub word: Option<String>,
+    pub random: Option<bool>,
+    pub seed: Option<u64>,
+    pub day: Option<usize>,
+    pub difficult: Option<bool>,
+    pub difficulty: Option<Difficulty>, // the --difficult flag stands for "hard"
+    pub stats: Option<bool>,
+    pub final_set: Option<String>,
+    pub acceptable_set: Option<String>,
+    pub alphabet: Option<String>, // a preset name or the letters, spelled out from the word lists if not given
+    pub state: Option<String>,
+    pub keyboard_layout: Option<String>, // a preset name or the path of a layout file
+    pub length: Option<usize>,
+    pub max_guesses: Option<usize>, // 0 for unlimited
+    pub boards: Option<usize>,
+    pub absurdle: Option<bool>,
+    pub daily: Option<bool>,
+    pub date: Option<String>, // the day of an archive daily puzzle, YYYY-MM-DD
+    pub epoch: Option<String>, // the day of the first daily puzzle, YYYY-MM-DD
+    pub share: Option<bool>,
+    pub share_ascii: Option<bool>, // share with letters in place of the emoji
+    pub share_file: Option<String>, // the file the share blocks are appended to, shared even without --share
+    pub challenge: Option<String>,  // the code of a challenge to play
+    pub speedrun: Option<usize>,    // the rounds of a speedrun
+    pub time_attack: Option<u64>,   // the minutes of a time attack
+    pub lang: Option<String>,       // the language of the messages, the locale if not given
+}
+
+// the result of a round of game: (win or lose, guesses used, max guesses allowed, seconds taken if timed)
+pub type Outcome = Option<(Option<bool>, Option<usize>, usize, Option<f64>)>;
+
+/**
+how much of what the earlier guesses revealed a new guess has to respect.
+the order is from the most lenient to the strictest.
+*/
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Normal, // any acceptable word
+    Hard,   // greens stay in place and yellows are reused
+    Strict, // every fact revealed so far: greys banned, yellows moved away from their slots
+}
+
+/**
+the fact of an earlier guess a word breaks in the hard or strict difficulty, the reason
+it is rejected, written in the language of the player with `Lang::text(Msg::Rule(rule))`.
+the positions are counted from 0.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    MustBe(usize, char),      // a green letter left its slot
+    MustContain(char, usize), // fewer of a yellow or green letter than found
+    CantBe(usize, char),      // a yellow letter put back in its slot
+    CantContain(char),        // a grey letter never found
+    TooMany(char, usize),     // more of a letter than found, a grey tells the exact number
+}
+
+// read from the value of --difficulty
+impl FromStr for Difficulty {
+    type Err = Error;
+    fn from_str(text: &str) -> Result<Difficulty, Error> {
+        match text {
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            "strict" => Ok(Difficulty::Strict),
+            _ => Err(Error::Args(format!(
+                "--difficulty {} is not normal, hard or strict",
+                text
+            ))),
+        }
+    }
+}
+
+// global varibles
+pub static DEFAULT_LIMIT: usize = 6; // default max trail
+pub static UNLIMITED: usize = 0; // max trail for the unlimited practice
+pub static DEFAULT_LENGTH: usize = 5; // default word length
+pub static MIN_LENGTH: usize = 3; // shortest word length allowed
+pub static MAX_LENGTH: usize = 12; // longest word length allowed
+pub static DEFAULT_SEED: u64 = 42; // default random seed
+pub static DEFAULT_BOARDS: usize = 1; // boards solved at once
+pub static MAX_BOARDS: usize = 8; // the most boards allowed, octordle
+pub static DEFAULT_EPOCH: &str = "2021-06-19"; // the day of the first daily puzzle
+
+/**
+function: to get a trimed String from the standard input
+input: None
+output: a trimed string from the standard input, an input error if it ended
+*/
+pub fn input() -> Result<String, Error> {
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Err(err) => return Err(Error::Input(err.to_string())),
+        Ok(0) => return Err(Error::Input(String::from("nothing more to read"))),
+        _ => {}
+    }
+    Ok(String::from(input.trim()))
+}
+
+/**
+function: to list the arguments that pick the word lists and the config file, taken by every subcommand that needs them
+input: None
+output: the arguments
+*/
+fn lists() -> Vec<Arg> {
+    vec![
+        Arg::new("final-set")
+            .long("final-set")
+            .short('f')
+            .value_name("FILE")
+            .help("The file of the answers, one word a line"),
+        Arg::new("acceptable-set")
+            .long("acceptable-set")
+            .short('a')
+            .value_name("FILE")
+            .help("The file of the words accepted as guesses, one word a line"),
+        Arg::new("alphabet")
+            .long("alphabet")
+            .value_name("ALPHABET")
+            .help(
+                "The letters the words are spelled with, or english, spanish, german, portuguese",
+            ),
+        Arg::new("length")
+            .long("length")
+            .short('l')
+            .value_name("LETTERS")
+            .help("The word length"),
+    ]
+    .into_iter()
+    .chain(configs())
+    .collect()
+}
+
+/**
+function: to list the arguments of the config file, taken by every command
+input: None
+output: the arguments
+*/
+fn configs() -> Vec<Arg> {
+    vec![
+        Arg::new("config")
+            .long("config")
+            .short('c')
+            .value_name("FILE")
+            .help("The config file, overridden by the arguments"),
+        Arg::new("profile")
+            .long("profile")
+            .short('p')
+            .value_name("NAME")
+            .help("The profile of the config file to play with, each one with a state file of its own"),
+    ]
+}
+
+/**
+function: to list the argument of the state file
+input: None
+output: the argument
+*/
+fn state() -> Arg {
+    Arg::new("state")
+        .long("state")
+        .short('S')
+        .value_name("FILE")
+        .help("The state file the rounds are kept in")
+}
+
+/**
+function: to list the argument of the language of the messages
+input: None
+output: the argument
+*/
+fn lang() -> Arg {
+    Arg::new("lang")
+        .long("lang")
+        .value_name("LANG")
+        .help("The language of the messages, en or zh, the locale if not given")
+}
+
+/**
+function: to add the arguments of a game to a command, taken by the game, "play" and "challenge create",
+            the modes that don't go together are rejected by the parser
+input: command: the command
+output: the command with the arguments
+*/
+fn game(command: Command) -> Command {
+    let flag = |name: &'static str| Arg::new(name).long(name).action(ArgAction::SetTrue);
+    let modes = ["random", "word", "daily", "date", "absurdle"];
+    command.args(lists()).arg(state()).args([
+        Arg::new("word")
+            .long("word")
+            .short('w')
+            .value_name("WORDS")
+            .help("The answer, one for each board separated by ','")
+            .conflicts_with("random"),
+        flag("difficult").short('D').help("Play in the hard mode"),
+        Arg::new("difficulty")
+            .long("difficulty")
+            .value_name("LEVEL")
+            .value_parser(["normal", "hard", "strict"])
+            .help("How much of what was revealed a guess has to respect"),
+        flag("absurdle")
+            .short('A')
+            .help("Play against an answer that dodges the guesses")
+            .conflicts_with_all(["random", "word"]),
+        flag("random")
+            .short('r')
+            .help("Play random answers, the next one after each round"),
+        flag("daily")
+            .help("Play the daily puzzle of today")
+            .conflicts_with_all(["random", "word", "day", "absurdle"]),
+        Arg::new("date")
+            .long("date")
+            .value_name("YYYY-MM-DD")
+            .help("Play the daily puzzle of a day before")
+            .conflicts_with_all(["random", "word", "day", "absurdle"]),
+        Arg::new("epoch")
+            .long("epoch")
+            .value_name("YYYY-MM-DD")
+            .help("The day of the first daily puzzle"),
+        Arg::new("code") // not "challenge", the name of the subcommand
+            .long("challenge")
+            .value_name("CODE")
+            .help("Play the challenge of the code")
+            .conflicts_with_all(modes),
+        Arg::new("speedrun")
+            .long("speedrun")
+            .value_name("ROUNDS")
+            .help("Play a number of seeded rounds against the clock")
+            .conflicts_with_all(["time-attack", "code"])
+            .conflicts_with_all(modes),
+        Arg::new("time-attack")
+            .long("time-attack")
+            .value_name("MINUTES")
+            .help("Play as many seeded rounds as the minutes allow")
+            .conflicts_with("code")
+            .conflicts_with_all(modes),
+        flag("share").help("Share each round as a grid of emoji"),
+        flag("share-ascii").help("Share with letters in place of the emoji"),
+        Arg::new("share-file")
+            .long("share-file")
+            .value_name("FILE")
+            .help("Append the shared grids to a file"),
+        Arg::new("day")
+            .long("day")
+            .short('d')
+            .value_name("DAY")
+            .help("Where in the shuffled answers to start, with random or a timed mode"),
+        Arg::new("seed")
+            .long("seed")
+            .short('s')
+            .value_name("SEED")
+            .help("The seed the answers are shuffled with, with random, daily or a timed mode"),
+        flag("stats")
+            .short('t')
+            .help("Show the statistics after each round"),
+        Arg::new("keyboard-layout")
+            .long("keyboard-layout")
+            .value_name("LAYOUT")
+            .help("qwerty, azerty, qwertz, dvorak, colemak or a file of the rows of keys"),
+        Arg::new("max-guesses")
+            .long("max-guesses")
+            .short('m')
+            .value_name("GUESSES")
+            .help("The guesses allowed for each board, or unlimited"),
+        Arg::new("boards")
+            .long("boards")
+            .short('b')
+            .value_name("BOARDS")
+            .help("The boards solved at once"),
+        lang(),
+    ])
+}
+
+/**
+function: to read the arguments of the game from the command line, for the frontends without subcommands
+input: name: the name of the program
+output: the arguments formed in ArgMatches, clap prints the usage error and exits with 2,
+        the code of an args error, if they are wrong or don't go together
+*/
+pub fn game_args(name: &'static str) -> ArgMatches {
+    game(Command::new(name)).get_matches()
+}
+
+/**
+function: to read the arguments of the wordle command from the command line, the game is played
+            with the arguments of "play" if no subcommand is given
+input: None
+output: the arguments formed in ArgMatches, with the subcommand if it is called, clap prints the
+        usage error and exits with 2, the code of an args error, if they are wrong or don't go together
+*/
+pub fn args() -> ArgMatches {
+    let round = Arg::new("round")
+        .value_name("ROUND")
+        .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+        .help("The round to replay, counted from 1, the last one if not given");
+    let clues = Arg::new("clues")
+        .value_name("GUESS:PATTERN")
+        .num_args(0..)
+        .help("A guess and its colors, like crane:RYRRG, G for green, Y for yellow and R for red");
+    game(Command::new("wordle"))
+        .about("A Wordle game, played with the arguments of \"play\" if no subcommand is given")
+        .args_conflicts_with_subcommands(true)
+        .subcommand(game(Command::new("play")).about("Play the game"))
+        .subcommand(
+            Command::new("solve")
+                .about("Narrow the answers down from the clues and suggest the next guess")
+                .args(lists())
+                .arg(clues)
+                .arg(lang()),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Show the statistics kept in a state file")
+                .arg(state())
+                .args(configs())
+                .arg(lang()),
+        )
+        .subcommand(
+            Command::new("dict")
+                .about("Check the word lists and show what they hold")
+                .args(lists())
+                .arg(lang()),
+        )
+        .subcommand(
+            Command::new("replay")
+                .about("Show the guesses of a round kept in a state file")
+                .arg(state())
+                .args(configs())
+                .arg(round)
+                .arg(lang()),
+        )
+        .subcommand(
+            game(Command::new("config")).about(
+                "Print the config a game would be played with and where each value comes from",
+            ),
+        )
+        .subcommand(
+            Command::new("challenge")
+                .about("Set a challenge for the others")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(game(Command::new("create")).about("Print the code of a challenge")),
+        )
+        .get_matches()
+}
+
+/**
+function: to tell whether an argument was given on the command line
+input: arg: the arguments read from the command line
+        name: the name of the argument
+output: true if it was given, false if it wasn't or the command doesn't take it
+*/
+fn present(arg: &ArgMatches, name: &str) -> bool {
+    arg.ids().any(|id| id == name) && arg.value_source(name) == Some(ValueSource::CommandLine)
+}
+
+/**
+function: to get the value of an argument
+input: arg: the arguments read from the command line
+        name: the name of the argument
+output: the value, None if it wasn't given or the command doesn't take it
+*/
+fn value<'a>(arg: &'a ArgMatches, name: &str) -> Option<&'a str> {
+    arg.try_get_one::<String>(name)
+        .ok()
+        .flatten()
+        .map(String::as_str)
+}
+
+/**
+function: to determine either the letter should be colored yellow or red
+input: ans: the answer of this round of game
+        guess: where the to-be-colored letter lies
+        num: the order of the to-be-colored letter in the guess
+output: true for yellow, false for red
+*/
+pub fn y_or_r(ans: &[char], guess: &[char], num: usize) -> bool {
+    let mut ans_count: i32 = 0;
+    let mut guess_order: i32 = 1;
+    for letter in ans.iter() {
+        if *letter == guess[num] {
+            ans_count += 1;
+        }
+    } // count the number of the target letter in the answer
+    for i in 0..num {
+        if guess[i] == guess[num] {
+            guess_order += 1;
+        }
+    } // count the number of the target letter in the guess before it
+    for i in num + 1..guess.len() {
+        if guess[i] == guess[num] && guess[i] == ans[i] {
+            guess_order += 1;
+        }
+    } //add the green target letter in the guess after it
+    guess_order <= ans_count
+}
+
+/**
+function: to check whether a word fits the requirments in the difficult mode
+input: word: the word to be checked
+        guess: an earlier valid guess
+        color: the color of that guess
+output: Ok if the word fits, the rule it breaks as the error if it doesn't
+*/
+pub fn diffcult(word: &str, guess: &str, color: &Feedback) -> Result<(), Rule> {
+    let word: Vec<char> = upper(word).chars().collect();
+    let guess: Vec<char> = upper(guess).chars().collect();
+    for (i, tile) in color.tiles().enumerate() {
+        if tile == Tile::Green && word[i] != guess[i] {
+            return Err(Rule::MustBe(i, guess[i]));
+        } // greens can't be changed
+    }
+    for (i, tile) in color.tiles().enumerate() {
+        if tile == Tile::Red {
+            continue;
+        }
+        let letter = guess[i];
+        let found = (0..guess.len())
+            .filter(|j| guess[*j] == letter && color.tile(*j) != Tile::Red)
+            .count(); // count the target letter in yellow or green in the guess
+        if word.iter().filter(|other| **other == letter).count() < found {
+            return Err(Rule::MustContain(letter, found));
+        } // not enough target letter
+    }
+    Ok(())
+}
+
+/**
+function: to check whether a word respects every fact a guess revealed, in the strict difficulty
+input: word: the word to be checked
+        guess: an earlier valid guess
+        color: the color of that guess
+output: Ok if the word fits, the rule it breaks as the error if it doesn't
+*/
+pub fn strict(word: &str, guess: &str, color: &Feedback) -> Result<(), Rule> {
+    diffcult(word, guess, color)?;
+    let word: Vec<char> = upper(word).chars().collect();
+    let guess: Vec<char> = upper(guess).chars().collect();
+    for (i, tile) in color.tiles().enumerate() {
+        if tile == Tile::Yellow && word[i] == guess[i] {
+            return Err(Rule::CantBe(i, guess[i]));
+        } // not in this slot
+    }
+    for (i, tile) in color.tiles().enumerate() {
+        if tile != Tile::Red {
+            continue;
+        }
+        let letter = guess[i];
+        let found = (0..guess.len())
+            .filter(|j| guess[*j] == letter && color.tile(*j) != Tile::Red)
+            .count();
+        if found == 0 && word.contains(&letter) {
+            return Err(Rule::CantContain(letter));
+        } // a grey letter never found
+        if word.iter().filter(|other| **other == letter).count() > found {
+            return Err(Rule::TooMany(letter, found));
+        } // a grey tells the exact number
+    }
+    Ok(())
+}
+
+/**
+function: to check whether a word fits the difficulty on every board still going on, against
+            every guess of the round so far
+input: level: the difficulty
+        word: the word to be checked
+        round_guesses: the valid guesses of this round so far
+        colors: the color of each of those guesses on each board, None for the boards solved before it
+output: Ok if the word fits, the rule it breaks as the error if it doesn't
+*/
+pub fn fits(
+    level: Difficulty,
+    word: &str,
+    round_guesses: &[String],
+    colors: &[Vec<Option<Feedback>>],
+) -> Result<(), Rule> {
+    let check = match level {
+        Difficulty::Normal => return Ok(()),
+        Difficulty::Hard => diffcult,
+        Difficulty::Strict => strict,
+    };
+    let boards = colors.first().map_or(0, |row| row.len());
+    for board in 0..boards {
+        let board_colors = colors.iter().filter_map(|row| row[board]);
+        if board_colors.clone().any(|color| color.is_win()) {
+            continue; // the board is over
+        }
+        for (guess, color) in round_guesses.iter().zip(board_colors) {
+            check(word, guess, &color)?;
+        }
+    }
+    Ok(())
+}
+
+/**
+function: to get the color of a word based on the answer
+input: ans: answer for this round of game, at most MAX_LENGTH letters as wordsets checks
+    guess: the word to be colored, as long as the answer
+output: the Feedback standing for the color of each letter
+*/
+pub fn color(ans: &str, guess: &str) -> Feedback {
+    let mut color = [Tile::Red; MAX_TILES];
+    let ans: Vec<char> = lower(ans).chars().collect();
+    let guess: Vec<char> = lower(guess).chars().collect();
+    for i in 0..ans.len() {
+        if guess[i] == ans[i] {
+            color[i] = Tile::Green;
+        } else if ans.contains(&guess[i]) && y_or_r(&ans, &guess, i) {
+            color[i] = Tile::Yellow;
+        } // red otherwise
+    }
+    Feedback::from_tiles(&color[..ans.len()]).expect("MAX_LENGTH is below MAX_TILES")
+}
+
+/**
+function: to count the letters a pattern gives away
+input: color: the pattern
+output: the number of greens and the number of yellows
+*/
+fn reveals(color: &Feedback) -> (usize, usize) {
+    let greens = color.tiles().filter(|tile| *tile == Tile::Green).count();
+    let yellows = color.tiles().filter(|tile| *tile == Tile::Yellow).count();
+    (greens, yellows)
+}
+
+/**
+function: to dodge a guess in the absurdle mode, grouping the candidates by the pattern they
+            would give and keeping the largest group, the one giving away less on a tie
+input: candidates: the words that can still be the answer, narrowed down to the kept group
+        guess: the valid guess
+output: the color of the guess on the kept group
+*/
+pub fn dodge(candidates: &mut Vec<String>, guess: &str) -> Feedback {
+    let length = guess.chars().count();
+    let mut counts = vec![0; Feedback::patterns(length) as usize];
+    for word in candidates.iter() {
+        counts[color(word, guess).code() as usize] += 1;
+    } // the size of each group
+    let mut kept: Option<(Feedback, usize)> = None;
+    for (code, count) in counts.iter().enumerate() {
+        if *count == 0 {
+            continue;
+        }
+        let pattern = Feedback::from_code(code as u32, length).unwrap();
+        kept = match kept {
+            Some((best, most))
+                if most > *count || (most == *count && reveals(&best) <= reveals(&pattern)) =>
+            {
+                Some((best, most))
+            }
+            _ => Some((pattern, *count)),
+        };
+    }
+    let kept = kept.unwrap().0; // there is always a candidate left
+    candidates.retain(|word| color(word, guess) == kept);
+    kept
+}
+
+/**
+function: to color a valid guess on every board still going on
+input: answers: the answer of each board, or the candidates left in the absurdle mode
+        absurd: the absurdle mode?
+        guess: the valid guess
+        total: the guesses used in this round, including this one
+        solved: the guess each board was solved at, None for the boards still going on
+output: the color of the guess on each board, None for the boards solved before it
+*/
+pub fn score(
+    answers: &mut Vec<String>,
+    absurd: bool,
+    guess: &str,
+    total: usize,
+    solved: &mut [Option<usize>],
+) -> Vec<Option<Feedback>> {
+    if absurd {
+        let color = dodge(answers, guess);
+        if color.is_win() {
+            solved[0] = Some(total); // committed to the guess, the only word left
+        }
+        return vec![Some(color)];
+    }
+    let mut colors = vec![];
+    for (ans, solved) in answers.iter().zip(solved.iter_mut()) {
+        if solved.is_some() {
+            colors.push(None);
+            continue;
+        }
+        let color = color(ans, guess);
+        if color.is_win() {
+            *solved = Some(total);
+        }
+        colors.push(Some(color));
+    }
+    colors
+}
+
+/**
+function: to get the total guesses allowed for a round
+input: limit: the max guesses allowed for a single board, UNLIMITED for no limit
+        boards: the number of boards solved at once
+output: the max guesses allowed for all the boards, one more for every extra board
+*/
+pub fn budget(limit: usize, boards: usize) -> usize {
+    if limit == UNLIMITED {
+        UNLIMITED
+    } else {
+        limit + boards - 1
+    }
+}
+
+/**
+function: to get the max guesses of a single board back from the ones of a round, as the state file keeps it
+input: limit: the max guesses allowed for all the boards, UNLIMITED for no limit
+        boards: the number of boards solved at once
+output: the max guesses allowed for a single board
+*/
+fn single(limit: usize, boards: usize) -> usize {
+    if limit == UNLIMITED {
+        UNLIMITED
+    } else {
+        limit + 1 - boards
+    }
+}
+
+/**
+function: to check whether the player has used up the guesses
+input: total: the guesses used in this round
+        limit: the max guesses allowed, UNLIMITED for no limit
+output: true if there is no guess left
+*/
+pub fn used_up(total: usize, limit: usize) -> bool {
+    limit != UNLIMITED && total >= limit
+}
+
+/**
+function: to get the answers when the answers are asigned rather than randomly picked
+input: word: the "--word" argument, the answers separated by ',' for several boards
+        dict: the word lists of the game
+        boards: the number of boards solved at once
+output: the answer of each board, from the "--word" argument or one line of input each,
+        an args error if an answer is not in the final set
+*/
+pub fn word(word: &Option<String>, dict: &Dictionary, boards: usize) -> Result<Vec<String>, Error> {
+    let answers: Vec<String> = match word {
+        Some(x) => x.split(',').map(|ans| ans.trim().to_string()).collect(),
+        None => {
+            let mut answers = vec![];
+            for _ in 0..boards {
+                answers.push(input()?); //input answer if not assigned
+            }
+            answers
+        }
+    };
+    if answers.len() != boards {
+        return Err(Error::Args(format!(
+            "{} answers are given for {} boards",
+            answers.len(),
+            boards
+        )));
+    }
+    for ans in answers.iter() {
+        if !dict.is_final(ans) {
+            return Err(Error::Args(format!(
+                "the answer \"{}\" is not in the final set",
+                ans
+            ))); //answer has to be in the final set
+        }
+    }
+    Ok(answers)
+}
+
+/**
+function: to pick the answers of a round from the shuffled final set, skipping the played ones
+input: list: the shuffled order of the final set
+        sub: where to start in the list, moved past the picked answers
+        dict: the word lists of the game
+        played: the answers of the rounds played before
+        boards: the number of boards solved at once
+output: the uppercase answer of each board, a word list error if the final set is used up
+*/
+pub fn pick(
+    list: &[usize],
+    sub: &mut usize,
+    dict: &Dictionary,
+    played: &[Vec<String>],
+    boards: usize,
+) -> Result<Vec<String>, Error> {
+    let mut answers: Vec<String> = vec![];
+    let mut tried = 0;
+    while answers.len() < boards {
+        if tried == list.len() {
+            return Err(Error::word_list(
+                "final-set",
+                &None,
+                String::from("every answer was played already"),
+            ));
+        }
+        let ans = upper(&dict.finals()[list[*sub]]);
+        *sub = (*sub + 1) % list.len(); //try next answer
+        tried += 1;
+        if played.iter().any(|round| round.contains(&ans)) || answers.contains(&ans) {
+            continue; // ensure the random answers don't duplicate
+        }
+        answers.push(ans);
+    }
+    Ok(answers)
+}
+
+/**
+function: to get an answer when the mode is set random
+input: day: the "--day" argument
+        seed: the "--seed" argument
+        list: a vec of usize number from 0 to the length of the final set
+output: the order of ( (the order of the answer in the final set) in the shuffled list )
+*/
+pub fn random(day: &Option<usize>, seed: &Option<u64>, list: &mut [usize]) -> usize {
+    let seed = seed.unwrap_or(DEFAULT_SEED); // None when random is only set in the config file
+    list.shuffle(&mut StdRng::seed_from_u64(seed));
+    day.unwrap_or(1) - 1
+}
+
+/**
+function: to read a date written as YYYY-MM-DD
+input: text: the date
+        name: the name of the date, used in the error message
+output: the date, an args error if it is not one
+*/
+fn date(text: &str, name: &str) -> Result<NaiveDate, Error> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .map_err(|_| Error::Args(format!("--{} {} is not a YYYY-MM-DD date", name, text)))
+}
+
+/**
+function: to get the daily puzzle to play, of today or of the "--date" in the archive
+input: config: the ultimate Config struct
+        dict: the word lists of the game
+output: None if the daily mode is off, otherwise the date of the puzzle and its day,
+        counted from the epoch and wrapped around the final set, to shuffle the final set with
+*/
+pub fn daily(config: &Config, dict: &Dictionary) -> Result<Option<(String, usize)>, Error> {
+    let today = Local::now().date_naive();
+    let day = match &config.date {
+        Some(text) => date(text, "date")?, // archive play
+        None if config.daily.is_some() && config.daily.unwrap() => today,
+        None => return Ok(None),
+    };
+    let epoch = date(config.epoch.as_deref().unwrap_or(DEFAULT_EPOCH), "epoch")?;
+    if day < epoch || day > today {
+        return Err(Error::Args(format!(
+            "there is no daily puzzle on {}, only from {} to {}",
+            day, epoch, today
+        ))); // no puzzle yet on that day
+    }
+    let days = (day - epoch).num_days() as usize;
+    Ok(Some((
+        day.format("%Y-%m-%d").to_string(),
+        days % dict.finals().len() + 1,
+    )))
+}
+
+/**
+function: to check that the daily puzzle of a date was not played yet
+input: records: how every round was played
+        today: the date of the daily puzzle to play
+output: Ok if it was not, a played error if it was
+*/
+pub fn unplayed(records: &[Details], today: &str) -> Result<(), Error> {
+    if records
+        .iter()
+        .any(|record| record.date.as_deref() == Some(today))
+    {
+        return Err(Error::Played {
+            date: String::from(today),
+        });
+    }
+    Ok(())
+}
+
+/**
+function: to start the record of a round, with the facts the config decides
+input: config: the ultimate Config struct
+        dict: the word lists of the game
+output: the mode, the difficulty, the seed if the answers are shuffled and the word lists,
+        the day and the date are left to the mode to fill in
+*/
+pub fn details(config: &Config, dict: &Dictionary) -> Details {
+    let on = |flag: Option<bool>| flag.is_some() && flag.unwrap();
+    let mode = if config.challenge.is_some() {
+        Mode::Challenge
+    } else if on(config.absurdle) {
+        Mode::Absurdle
+    } else if config.date.is_some() || on(config.daily) {
+        Mode::Daily
+    } else if config.speedrun.is_some() {
+        Mode::Speedrun
+    } else if config.time_attack.is_some() {
+        Mode::TimeAttack
+    } else if on(config.random) {
+        Mode::Random
+    } else if config.word.is_some() {
+        Mode::Word
+    } else {
+        Mode::Input
+    }; // in the order the modes are told apart when played
+    let shuffled = matches!(
+        mode,
+        Mode::Random | Mode::Daily | Mode::Speedrun | Mode::TimeAttack
+    );
+    Details {
+        mode: Some(mode),
+        difficulty: Some(level(config)),
+        seed: if shuffled {
+            config.seed.or(Some(DEFAULT_SEED))
+        } else {
+            None
+        },
+        word_lists: Some(format!("{:08x}", dict.identity())),
+        ..Details::default()
+    }
+}
+
+/**
+function: to check that a round left in the middle can be continued with the word lists of the game
+input: unfinished: the round left in the middle
+        dict: the word lists of the game
+output: true if it was played with the same word lists
+*/
+pub fn resumable(unfinished: &Unfinished, dict: &Dictionary) -> bool {
+    unfinished.details.word_lists == Some(format!("{:08x}", dict.identity()))
+}
+
+/**
+function: to set the game up the way a round left in the middle was played
+input: config: the ultimate Config struct
+        unfinished: the round left in the middle
+output: the Config of the round, the statistics and the sharing kept from config
+*/
+pub fn resumed(config: &Config, unfinished: &Unfinished) -> Config {
+    let absurd = unfinished.details.mode == Some(Mode::Absurdle);
+    Config {
+        random: Some(false),
+        difficult: Some(false),
+        difficulty: unfinished.details.difficulty,
+        max_guesses: Some(unfinished.max_guesses),
+        boards: Some(unfinished.answers.len().max(1)), // a single board in the absurdle mode
+        absurdle: Some(absurd),
+        daily: Some(false),
+        date: None,
+        challenge: None,
+        speedrun: None,
+        time_attack: None,
+        ..config.clone()
+    }
+}
+
+/**
+function: to find where an answer is in the shuffled final set
+input: list: the shuffled order of the final set
+        dict: the word lists of the game
+        answer: the answer, in any case
+output: the day that starts the shuffled final set at the answer, None if it is not in it
+*/
+pub fn place(list: &[usize], dict: &Dictionary, answer: &str) -> Option<usize> {
+    let answer = lower(answer);
+    list.iter()
+        .position(|i| dict.finals()[*i] == answer)
+        .map(|i| i + 1)
+}
+
+/**
+function: to tell the time, to record when a round started and finished
+input: None
+output: the local time in RFC 3339, to the second
+*/
+pub fn now() -> String {
+    Local::now().format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+}
+
+/**
+function: to check whether the result of every round is shared when it ends
+input: config: the ultimate Config struct
+output: true if "--share" or "--share-file" is given
+*/
+pub fn sharing(config: &Config) -> bool {
+    (config.share.is_some() && config.share.unwrap()) || config.share_file.is_some()
+}
+
+/**
+function: to build the spoiler-free share block of a round, a header like "Wordle 123 4/6*"
+            followed by a row of tiles for each guess, the boards side by side
+input: number: the number of the puzzle, its day for the daily puzzle
+        colors: the color of each guess on each board, None for the boards solved before it
+        outcome: the result of the round
+        level: the difficulty, marked with a "*" when it is hard or strict
+        ascii: whether the tiles are written as "G", "Y", "-" (and "." for a solved board)
+            in place of the emoji
+output: the block, ending with a newline
+*/
+pub fn grid(
+    number: usize,
+    colors: &[Vec<Option<Feedback>>],
+    outcome: &Outcome,
+    level: Difficulty,
+    ascii: bool,
+) -> String {
+    let (win, total, limit, _) = outcome.unwrap_or((None, None, UNLIMITED, None));
+    let mut block = format!("Wordle {} ", number);
+    match (win, total) {
+        (Some(true), Some(total)) => block += &total.to_string(),
+        _ => block += "X",
+    }
+    if limit != UNLIMITED {
+        block += &format!("/{}", limit);
+    }
+    if level >= Difficulty::Hard {
+        block += "*";
+    }
+    block += "\n";
+    for color in colors.iter() {
+        let length = color.iter().flatten().map(|board| board.len()).next(); // a board is left to guess
+        let length = length.unwrap_or(0);
+        let row: Vec<String> = color
+            .iter()
+            .map(|board| match board {
+                Some(board) => board
+                    .tiles()
+                    .map(|tile| match (tile, ascii) {
+                        (Tile::Green, false) => "\u{1f7e9}",
+                        (Tile::Yellow, false) => "\u{1f7e8}",
+                        (Tile::Red, false) => "\u{2b1b}",
+                        (Tile::Green, true) => "G",
+                        (Tile::Yellow, true) => "Y",
+                        (Tile::Red, true) => "-",
+                    })
+                    .collect(),
+                None if ascii => ".".repeat(length),
+                None => "\u{2b1c}".repeat(length),
+            })
+            .collect();
+        block += &row.join(" ");
+        block += "\n";
+    }
+    block
+}
+
+/**
+function: to write a share block out
+input: file: the file the block is appended to, None to print it
+        block: the share block
+output: None, a share error if the file can't be written
+*/
+pub fn share(file: &Option<String>, block: &str) -> Result<(), Error> {
+    match file {
+        Some(path) => std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(block.as_bytes()))
+            .map_err(|source| Error::Share {
+                path: path.clone(),
+                source,
+            }),
+        None => {
+            print!("{}", block);
+            Ok(())
+        }
+    }
+}
+
+/**
+function: compare the tuple of the word and its occurence first according occurence, then alphabet
+input: (guess1,time1), (guess2,time2) are the tuples of word and their times of occurence to be compared
+output: Greater if the latter is more prioritiesd (the Greater, the further back to display)
+*/
+pub fn compare((guess1, time1): (&String, &i32), (guess2, time2): (&String, &i32)) -> Ordering {
+    if time1 > time2 {
+        Ordering::Less // more occurrence, Less, former to display
+    } else if time1 < time2 {
+        Ordering::Greater
+    } else {
+        if guess1 > guess2 {
+            Ordering::Greater
+        } else if guess1 < guess2 {
+            Ordering::Less
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+// the guesses the distribution of the rounds won is counted up to, the last one counts the more
+pub const DISTRIBUTION: usize = 6;
+
+/**
+the statistics of the rounds played, the saved ones and the ones of this run.
+the rounds whose result can't be told (no answer or no guess saved) are left out.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub wins: usize,
+    pub losses: usize,
+    pub average: f64, // the average guesses of the rounds won, 0 if none
+    pub words: Vec<(String, i32)>, // the words guessed and how many times, the most used first
+    pub distribution: [usize; DISTRIBUTION], // the rounds won in 1, 2, ... 6 or more guesses
+    pub current_streak: usize, // the rounds won in a row up to the last one
+    pub max_streak: usize, // the most rounds ever won in a row
+    pub best_time: Option<f64>, // the fastest round won with its time kept
+    pub average_time: Option<f64>, // the average of the rounds won with their time kept
+}
+
+impl Stats {
+    /**
+    function: to count the rounds played
+    input: None
+    output: the rounds won and lost
+    */
+    pub fn played(&self) -> usize {
+        self.wins + self.losses
+    }
+
+    /**
+    function: to get the share of the rounds won
+    input: None
+    output: the percentage, 0 if no round is played
+    */
+    pub fn win_rate(&self) -> f64 {
+        if self.played() == 0 {
+            0.0
+        } else {
+            self.wins as f64 * 100.0 / self.played() as f64
+        }
+    }
+
+    /**
+    function: to get the guess distribution with the failed rounds, to be drawn as a histogram
+    input: None
+    output: the label and the count of each bar, "1" to "5", "6+" for 6 guesses or more
+            and "X" for the failed rounds
+    */
+    pub fn bars(&self) -> Vec<(String, usize)> {
+        let mut bars: Vec<(String, usize)> = self
+            .distribution
+            .iter()
+            .enumerate()
+            .map(|(i, count)| match i + 1 {
+                DISTRIBUTION => (format!("{}+", DISTRIBUTION), *count), // more guesses allowed with --max-guesses or --boards
+                guesses => (guesses.to_string(), *count),
+            })
+            .collect();
+        bars.push((String::from("X"), self.losses));
+        bars
+    }
+}
+
+/**
+function: to get the statistics from guesses and results
+input: guesses: a vector of all the valid guessed the player inputs
+        results: a vector of all the win/lose results and the trails used to win a game
+output: the statistics
+*/
+pub fn stats(guesses: &[String], results: &[Outcome]) -> Stats {
+    let mut output = Stats {
+        wins: 0,
+        losses: 0,
+        average: 0.0,
+        words: vec![],
+        distribution: [0; DISTRIBUTION],
+        current_streak: 0,
+        max_streak: 0,
+        best_time: None,
+        average_time: None,
+    };
+    let mut tryout = 0;
+    let mut times: Vec<f64> = vec![];
+    for (win, total, _, time) in results.iter().flatten() {
+        match win {
+            Some(true) => {
+                output.wins += 1;
+                let total = total.unwrap();
+                tryout += total;
+                output.distribution[total.clamp(1, DISTRIBUTION) - 1] += 1;
+                output.current_streak += 1;
+                output.max_streak = output.max_streak.max(output.current_streak);
+                if let Some(time) = time {
+                    times.push(*time);
+                }
+            }
+            Some(false) => {
+                output.losses += 1;
+                output.current_streak = 0; // the streak is broken
+            }
+            None => {} // can't decide win/lose
+        }
+    }
+    if output.wins > 0 {
+        output.average = tryout as f64 / output.wins as f64;
+    }
+    if !times.is_empty() {
+        output.best_time = times.iter().copied().reduce(f64::min);
+        output.average_time = Some(times.iter().sum::<f64>() / times.len() as f64);
+    }
+    for i in guesses {
+        let mut contain = false;
+        for j in output.words.iter_mut() {
+            if *i == j.0 {
+                j.1 += 1; // count j.0's occurence
+                contain = true;
+                break;
+            }
+        }
+        if !contain {
+            // new word
+            output.words.push((i.to_string(), 1));
+        }
+    }
+    output
+        .words
+        .sort_by(|(a, b), (c, d)| compare((a, b), (c, d)));
+    output
+}
+
+/**
+function: to get the seconds a round has taken
+input: start: when the round started
+output: the seconds, to the millisecond
+*/
+pub fn seconds(start: Instant) -> f64 {
+    (start.elapsed().as_secs_f64() * 1000.0).round() / 1000.0
+}
+
+/**
+function: to write seconds as a clock
+input: seconds: the seconds
+output: the clock, like "1:03.25"
+*/
+pub fn clock(seconds: f64) -> String {
+    let hundredths = (seconds * 100.0).round() as u64;
+    format!(
+        "{}:{:02}.{:02}",
+        hundredths / 6000,
+        hundredths / 100 % 60,
+        hundredths % 100
+    )
+}
+
+/**
+function: to get when the time of a time attack is up
+input: config: the ultimate Config struct
+output: the deadline from now, None if it is not a time attack
+*/
+pub fn deadline(config: &Config) -> Option<Instant> {
+    config
+        .time_attack
+        .map(|minutes| Instant::now() + Duration::from_secs(minutes * 60))
+}
+
+/**
+function: to check whether the time of a time attack is up
+input: deadline: when the time is up, None if it is not a time attack
+output: true if it is up
+*/
+pub fn time_up(deadline: Option<Instant>) -> bool {
+    deadline.is_some() && Instant::now() >= deadline.unwrap()
+}
+
+/**
+function: to get the contents in a file
+input: instructions: the argument from "--acceptable" or "--final" or "--config" or "--state"
+output: the contents of the file, the io error if it can't be read, turned into
+        the error of the file by the caller
+*/
+pub fn fread(instruction: &Option<String>) -> io::Result<String> {
+    let mut text: String = String::new();
+    std::fs::File::open(instruction.as_ref().unwrap())?.read_to_string(&mut text)?;
+    Ok(text)
+}
+
+/**
+function: to check whether a word is a word of the given length
+input: word: the word to be checked
+        length: the word length of the game, counted in letters
+output: true if it passes the check, false if it doesn't
+*/
+pub fn wordcheck(word: &str, length: usize) -> bool {
+    if word.chars().count() != length {
+        return false;
+    } else {
+        for letter in word.chars() {
+            if !letter.is_alphabetic() {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/**
+function: to read a word list from its file, or from the built-in list if no file is given
+input: file: the "--final-set" or "--acceptable-set" argument
+        builtin: the built-in list to fall back on
+        name: the name of the list, used in the error messages
+        length: the word length of the game
+output: the sorted Vec of the lowercase words in the list, a word list error pointing at
+        the line of the first wrong word
+*/
+pub fn wordlist(
+    file: &Option<String>,
+    builtin: &[&str],
+    name: &str,
+    length: usize,
+) -> Result<Vec<String>, Error> {
+    let mut words: Vec<(String, usize)> = match file {
+        Some(_) => fread(file)
+            .map_err(|err| Error::word_list(name, file, format!("can't be read ({})", err)))?
+            .lines()
+            .enumerate()
+            .map(|(line, word)| (lower(word.trim()), line + 1))
+            .filter(|(word, _)| !word.is_empty())
+            .collect(),
+        None => builtin
+            .iter()
+            .filter(|word| word.chars().count() == length)
+            .map(|word| (word.to_string(), 0))
+            .collect(),
+    }; // each word with its line, 0 for the built-in lists
+    let wrong = |word: &String, line: usize, reason: String| Error::WordList {
+        list: String::from(name),
+        path: file.clone(),
+        line: Some(line).filter(|line| *line > 0),
+        word: Some(word.clone()),
+        reason,
+    };
+    if words.is_empty() {
+        return Err(Error::word_list(
+            name,
+            file,
+            format!("has no {}-letter word", length),
+        ));
+    }
+    for (word, line) in words.iter() {
+        if !wordcheck(word, length) {
+            return Err(wrong(
+                word,
+                *line,
+                format!("is not a {}-letter word", length),
+            ));
+        } //check every word against the length
+    }
+    words.sort();
+    for i in 1..words.len() {
+        if words[i].0 == words[i - 1].0 {
+            return Err(wrong(
+                &words[i].0,
+                words[i].1,
+                format!("appears twice, first on line {}", words[i - 1].1),
+            ));
+        } // duplicate?
+    }
+    Ok(words.into_iter().map(|(word, _)| word).collect())
+}
+
+/**
+function: to set the finalset and the acceptable set
+input: finalset: the "--finalset" argument
+        acceptset: the "--acceptable" argument
+        alphabet: the "--alphabet" argument
+        length: the word length of the game
+output: the Dictionary of the final set and the acceptable set, a word list error
+        if they are wrong or don't go together
+*/
+pub fn set(
+    finalset: &Option<String>,
+    acceptset: &Option<String>,
+    alphabet: &Option<String>,
+    length: usize,
+) -> Result<Dictionary, Error> {
+    let finalvec = wordlist(finalset, FINAL, "final-set", length)?;
+    let acceptvec = wordlist(acceptset, ACCEPTABLE, "acceptable-set", length)?;
+    let wrong = |word: &String, reason: &str| Error::WordList {
+        list: String::from("acceptable-set"),
+        path: acceptset.clone(),
+        line: None,
+        word: Some(word.clone()),
+        reason: String::from(reason),
+    };
+    for word in finalvec.iter() {
+        if acceptvec.binary_search(word).is_err() {
+            return Err(wrong(word, "of the final set is missing"));
+        } // cover?
+    }
+    let alphabet = match alphabet {
+        Some(text) => Alphabet::new(text)?,
+        None => Alphabet::spelling(&acceptvec), // the acceptable set covers the final set
+    };
+    if let Some(word) = acceptvec
+        .iter()
+        .find(|word| !word.chars().all(|letter| alphabet.contains(letter)))
+    {
+        return Err(wrong(word, "is spelled out of the alphabet"));
+    }
+    Ok(Dictionary::new(finalvec, acceptvec, alphabet))
+}
+
+/**
+function: to load state from json file
+input: state: the "--state"
+        answers: the vec stores the answers of every board of all the rounds
+        records: the vec stores how every round was played
+        guesses: the vec stores all the guesses
+        results: the vec stores all the results including wins/lose, mean trails to win
+        unfinished: where stores the round left in the middle, if there is one
+output: true if the file was read, false if it doesn't exist yet and the game starts clean,
+        a state error if the file can't be read or is of a version this program doesn't know
+*/
+pub fn stateload(
+    state: &Option<String>,
+    answers: &mut Vec<Vec<String>>,
+    records: &mut Vec<Details>,
+    guesses: &mut Vec<String>,
+    results: &mut Vec<Outcome>,
+    unfinished: &mut Option<Unfinished>,
+) -> Result<bool, Error> {
+    if !Path::new(state.as_ref().unwrap()).exists() {
+        return Ok(false); // created by the first save
+    }
+    let contents =
+        fread(state).map_err(|err| Error::state(state, format!("can't be read ({})", err)))?;
+    let value: serde_json::Value = match serde_json::from_str(&contents) {
+        Ok(value) => value,
+        Err(err) => return Err(Error::state(state, format!("not a state file ({})", err))),
+    };
+    // the version is read first, a newer file may not fit the struct at all
+    match value.get("version").map(|version| version.as_u64()) {
+        None => {} // version 1, read as version 2 without the details
+        Some(Some(version)) if (1..=STATE_VERSION as u64).contains(&version) => {}
+        Some(Some(version)) if version > STATE_VERSION as u64 => {
+            return Err(Error::state(
+                state,
+                format!(
+                    "version {} is newer than this program, which reads up to version {}",
+                    version, STATE_VERSION
+                ),
+            ))
+        }
+        Some(_) => {
+            return Err(Error::state(
+                state,
+                format!("unknown version {}", value.get("version").unwrap()),
+            ))
+        }
+    }
+    let text: Content = match serde_json::from_value(value) {
+        Ok(text) => text,
+        Err(err) => return Err(Error::state(state, format!("not a state file ({})", err))),
+    };
+    if let Some(games) = text.games {
+        for i in games {
+            let round: Vec<String> = match (i.answers, i.answer) {
+                (Some(boards), _) => boards,
+                (None, Some(answer)) => vec![answer],
+                (None, None) => vec![],
+            }; // several boards, a single board or no answer
+            let limit = budget(i.max_guesses.unwrap_or(DEFAULT_LIMIT), round.len().max(1));
+            answers.push(round.clone()); // deal answers
+            records.push(i.details.clone());
+            if i.guesses.as_ref().is_some() {
+                for j in 0..i.guesses.as_ref().unwrap().len() {
+                    guesses.push(i.guesses.as_ref().unwrap()[j].clone()); // deal guesses
+                }
+                if !round.is_empty() {
+                    // answer and guesses both are some
+                    if round
+                        .iter()
+                        .all(|answer| i.guesses.as_ref().unwrap().contains(answer))
+                    {
+                        // every answer guessed, win
+                        results.push(Some((
+                            Some(true),
+                            Some(i.guesses.as_ref().unwrap().len()),
+                            limit,
+                            i.time,
+                        )));
+                    } else {
+                        //otherwisw, lose
+                        results.push(Some((
+                            Some(false),
+                            Some(i.guesses.as_ref().unwrap().len()),
+                            limit,
+                            i.time,
+                        )));
+                    }
+                } else {
+                    // answer is none, guesses is some, can't decide win/lose
+                    results.push(Some((None, Some(i.guesses.unwrap().len()), limit, i.time)));
+                }
+            } else {
+                // guesses is none
+                results.push(None);
+            } // deal results
+        }
+    }
+    *unfinished = text.unfinished;
+    Ok(true)
+}
+
+/**
+function: to save state into json file
+input: state: the "--state"
+        answers: the vec stores the answers of every board of all the rounds
+        records: the vec stores how every round was played
+        guesses: the vec stores all the guesses
+        results: the vec stores all the results including wins/lose, mean trails to win
+        unfinished: the round going on, saved to be continued if the game stops in the middle
+output: None, the file is always written in the latest version.
+        the file is written aside and renamed over the old one, which is kept as `<state>.bak`,
+        so that a crash in the middle of a save never leaves a broken file behind
+*/
+pub fn statesave(
+    state: &Option<String>,
+    answers: &[Vec<String>],
+    records: &[Details],
+    guesses: &[String],
+    results: &[Outcome],
+    unfinished: Option<&Unfinished>,
+) -> Result<(), Error> {
+    if state.is_none() {
+        return Ok(());
+    }
+    let rounds = answers.len().min(results.len()); // a round not over yet is left out
+    let mut games = vec![];
+    for i in 0..rounds {
+        let mut former = 0;
+        for result in results.iter().take(i) {
+            if result.is_some() && (result.unwrap().1).is_some() {
+                former += (result.unwrap().1).unwrap();
+            }
+        } //count guesses before this round
+        let mut guess: Vec<String> = vec![];
+        if results[i].is_some() && (results[i].unwrap().1).is_some() {
+            for j in 0..(results[i].unwrap().1).unwrap() {
+                guess.push(upper(&guesses[former + j]));
+            } //guess if the guesses for this round
+        }
+        let round: Vec<String> = answers[i].iter().map(|answer| upper(answer)).collect(); // the answer of every board for this round
+        let (answer, boards, solved) = if round.len() > 1 {
+            let solved = round
+                .iter()
+                .map(|answer| guess.iter().position(|g| g == answer).map(|num| num + 1))
+                .collect();
+            (None, Some(round), Some(solved))
+        } else {
+            (round.first().cloned(), None, None)
+        }; // a single board keeps the old format
+        let mut guess = Some(guess);
+        if guess.as_ref().unwrap().is_empty() {
+            guess = None;
+        } //empty guess save as None
+        let max_guesses = results[i]
+            .map(|(_, _, limit, _)| single(limit, answers[i].len().max(1)))
+            .filter(|limit| *limit != DEFAULT_LIMIT); // only the limits other than the default are saved
+        let r = Round {
+            answer,
+            guesses: guess,
+            max_guesses,
+            answers: boards,
+            solved,
+            time: results[i].and_then(|(_, _, _, time)| time),
+            details: records.get(i).cloned().unwrap_or_default(),
+        }; // a Round can never be empty
+        games.push(r);
+    }
+    let mut games = Some(games);
+    if games.as_ref().unwrap().is_empty() {
+        games = None;
+    } // an empty games save as None
+    let text = Content {
+        version: Some(STATE_VERSION),
+        total_rounds: Some(rounds),
+        games,
+        unfinished: unfinished.cloned(),
+    };
+    let text = serde_json::to_string_pretty(&text).unwrap();
+    let path = state.as_ref().unwrap();
+    let temp = format!("{}.tmp", path);
+    let written = std::fs::File::create(&temp).and_then(|mut file| {
+        file.write_all(text.as_bytes())?;
+        file.sync_all() // on the disk before it takes the place of the old file
+    });
+    let failed = |doing: &str, err: io::Error| Error::state(state, format!("{} ({})", doing, err));
+    written.map_err(|err| failed("can't be written", err))?;
+    if Path::new(path).exists() {
+        std::fs::copy(path, format!("{}.bak", path))
+            .map_err(|err| failed("can't be backed up", err))?;
+    }
+    std::fs::rename(&temp, path).map_err(|err| failed("can't be replaced", err))?;
+    Ok(())
+}
+
+/**
+function: to transform a brach of argument to a Option<String>
+input: arg: the arguments read from the command line
+        name: the name of the branch to be transformed
+output: None if the argument dosen't exist, Option<String> with its value if it does
+*/
+pub fn arg2opstring(arg: &ArgMatches, name: &str) -> Option<String> {
+    value(arg, name).map(String::from)
+}
+
+/**
+function: to get the difficulty of the game, the stricter one of --difficult and --difficulty
+input: config: the ultimate Config struct
+output: the difficulty
+*/
+pub fn level(config: &Config) -> Difficulty {
+    let hard = if config.difficult.is_some() && config.difficult.unwrap() {
+        Difficulty::Hard
+    } else {
+        Difficulty::Normal
+    };
+    config.difficulty.unwrap_or(Difficulty::Normal).max(hard)
+}
+
+/**
+function: to transform the arguments read from the command line into a Config struct
+input: arg: the arguments read from the command line
+output: the Config form of the arguments, an args error if a value is wrong
+*/
+pub fn arg2config(arg: &ArgMatches) -> Result<Config, Error> {
+    let wrong = |name: &str, value: &str, expected: &str| {
+        Error::Args(format!("--{} {} is not {}", name, value, expected))
+    };
+    let word: Option<String> = value(arg, "word").map(|word| word.to_string());
+    let _seed: Option<u64> = match value(arg, "seed") {
+        Some(seedstr) => match seedstr.parse::<u64>() {
+            Ok(seed) => Some(seed),
+            Err(_) => return Err(wrong("seed", seedstr, "a number")),
+        },
+        None => Some(DEFAULT_SEED), // seed not present or present with no value
+    };
+    let _day: Option<usize> = match value(arg, "day") {
+        Some(daystr) => match daystr.parse::<usize>() {
+            Ok(day) => Some(day), // checked against the final set in wordsets
+            Err(_) => return Err(wrong("day", daystr, "a number")), // day present with not a usize
+        },
+        None => Some(1), // day not present or present with no value
+    };
+    let _length: Option<usize> = match value(arg, "length") {
+        Some(lengthstr) => match lengthstr.parse::<usize>() {
+            Ok(length) => Some(length),
+            Err(_) => return Err(wrong("length", lengthstr, "a number")),
+        },
+        None => Some(DEFAULT_LENGTH), // length not present or present with no value
+    };
+    let _max_guesses: Option<usize> = match value(arg, "max-guesses") {
+        Some("unlimited") => Some(UNLIMITED),
+        Some(limitstr) => match limitstr.parse::<usize>() {
+            Ok(limit) => Some(limit),
+            Err(_) => return Err(wrong("max-guesses", limitstr, "a number or unlimited")),
+        },
+        None => Some(DEFAULT_LIMIT), // max guesses not present or present with no value
+    };
+    let _boards: Option<usize> = match value(arg, "boards") {
+        Some(boardsstr) => match boardsstr.parse::<usize>() {
+            Ok(boards) => Some(boards), // checked against the final set in wordsets
+            Err(_) => return Err(wrong("boards", boardsstr, "a number")),
+        },
+        None => Some(DEFAULT_BOARDS), // boards not present or present with no value
+    };
+    let difficulty: Option<Difficulty> = match value(arg, "difficulty") {
+        Some(levelstr) => Some(levelstr.parse::<Difficulty>()?),
+        None => None, // the --difficult flag alone, or normal
+    };
+    Ok(Config {
+        word,
+        random: Some(present(arg, "random")),
+        seed: _seed,
+        day: _day,
+        difficult: Some(present(arg, "difficult")),
+        difficulty,
+        absurdle: Some(present(arg, "absurdle")),
+        daily: Some(present(arg, "daily")),
+        date: arg2opstring(arg, "date"),
+        epoch: arg2opstring(arg, "epoch"),
+        share: Some(present(arg, "share")),
+        share_ascii: Some(present(arg, "share-ascii")),
+        share_file: arg2opstring(arg, "share-file"),
+        challenge: arg2opstring(arg, "code"),
+        speedrun: match value(arg, "speedrun") {
+            Some(rounds) => match rounds.parse::<usize>() {
+                Ok(rounds) if rounds > 0 => Some(rounds),
+                _ => return Err(wrong("speedrun", rounds, "a number of rounds above 0")),
+            },
+            None => None,
+        },
+        time_attack: match value(arg, "time-attack") {
+            Some(minutes) => match minutes.parse::<u64>() {
+                Ok(minutes) if minutes > 0 => Some(minutes),
+                _ => return Err(wrong("time-attack", minutes, "a number of minutes above 0")),
+            },
+            None => None,
+        },
+        stats: Some(present(arg, "stats")),
+        final_set: arg2opstring(arg, "final-set"),
+        acceptable_set: arg2opstring(arg, "acceptable-set"),
+        alphabet: arg2opstring(arg, "alphabet"),
+        state: arg2opstring(arg, "state"),
+        keyboard_layout: arg2opstring(arg, "keyboard-layout"),
+        lang: arg2opstring(arg, "lang"),
+        length: _length,
+        max_guesses: _max_guesses,
+        boards: _boards,
+    })
+}
+
+/**
+function: when the config file and the command line arguments both exist,
+            call this function to modify the Config struct from the command line arguments
+input: arg: the arguments read from the command line
+output: the Config struct from arg
+*/
+pub fn arg2config_modifier(arg: &ArgMatches) -> Result<Config, Error> {
+    let mut config = arg2config(arg)?;
+    if !present(arg, "random") {
+        config.random = None; // not Some(false)
+    }
+    if !present(arg, "seed") {
+        config.seed = None; // not Some(default)
+    }
+    if !present(arg, "day") {
+        config.day = None;
+    }
+    if !present(arg, "difficult") {
+        config.difficult = None;
+    }
+    if !present(arg, "absurdle") {
+        config.absurdle = None;
+    }
+    if !present(arg, "daily") {
+        config.daily = None;
+    }
+    if !present(arg, "share") {
+        config.share = None;
+    }
+    if !present(arg, "share-ascii") {
+        config.share_ascii = None;
+    }
+    if !present(arg, "stats") {
+        config.stats = None;
+    }
+    if !present(arg, "length") {
+        config.length = None;
+    }
+    if !present(arg, "max-guesses") {
+        config.max_guesses = None;
+    }
+    if !present(arg, "boards") {
+        config.boards = None;
+    }
+    Ok(config)
+}
+
+/**
+function: to read the config file of the arguments, the one given with "--config" or the one discovered
+input: arg: the arguments read from the command line
+output: the config file, with the profile of "--profile" laid over it, None if there is no config file,
+        a config error if it can't be read or is wrong
+*/
+fn configfile(arg: &ArgMatches) -> Result<Option<ConfigFile>, Error> {
+    let profile = value(arg, "profile");
+    match (arg2opstring(arg, "config").or_else(discover), profile) {
+        (Some(path), _) => Ok(Some(ConfigFile::read(&path, profile)?)),
+        (None, Some(profile)) => Err(Error::Args(format!(
+            "--profile {} needs a config file, given with --config or discovered",
+            profile
+        ))),
+        (None, None) => Ok(None),
+    }
+}
+
+/**
+function: to tell where each value of the ultimate Config struct comes from, for the "config" subcommand
+input: arg: the arguments read from the command line
+output: the key, the value and the source of every value set, sorted by the key,
+        the source is "command line", "challenge", the path and the line in the config file or "default",
+        the answer of a challenge left out
+*/
+pub fn sources(arg: &ArgMatches) -> Result<Vec<(String, serde_json::Value, String)>, Error> {
+    let ultimate = serde_json::to_value(config(arg)?).unwrap();
+    let given = serde_json::to_value(arg2config_modifier(arg)?).unwrap();
+    let file = configfile(arg)?;
+    let challenged = ["word", "length", "boards", "difficulty"]; // set by the code of a challenge
+    let mut sources = vec![];
+    for (key, value) in ultimate.as_object().unwrap() {
+        if value.is_null() {
+            continue; // not set at all
+        }
+        if key == "word" && !ultimate["challenge"].is_null() {
+            continue; // the answer of a challenge is never shown
+        }
+        let source = if !given[key].is_null() {
+            String::from("command line")
+        } else if !ultimate["challenge"].is_null() && challenged.contains(&key.as_str()) {
+            String::from("challenge")
+        } else if let Some(file) = file
+            .as_ref()
+            .filter(|file| !serde_json::to_value(&file.config).unwrap()[key].is_null())
+        {
+            file.origin(key)
+        } else {
+            String::from("default")
+        };
+        sources.push((key.clone(), value.clone(), source));
+    }
+    Ok(sources)
+}
+
+/**
+function: to form the ultimat Config struct for the program under every condition
+input: arg: the arguments read from the commnd line
+output: the ultimat Config struct for the program under every condition, a config error
+        if the config file is wrong or doesn't go together with the arguments
+*/
+pub fn config(arg: &ArgMatches) -> Result<Config, Error> {
+    // the checks are made on the merged values, a mode may be set in the file and its seed on the command line
+    let file = configfile(arg)?;
+    let config = file
+        .as_ref()
+        .map_or_else(Config::default, |file| file.config.clone());
+    let config = {
+        let mut args = arg2config_modifier(arg)?; // forming args
+        let given = serde_json::to_value(&args).unwrap(); // the keys set on the command line
+                                                          // dealing conflicts
+        if args.random.is_none() && config.random.is_some() {
+            args.random = config.random;
+        }
+        if args.word.is_none() && config.word.is_some() {
+            args.word = config.word;
+        }
+        if args.difficult.is_none() && config.difficult.is_some() {
+            args.difficult = config.difficult;
+        }
+        if args.difficulty.is_none() && config.difficulty.is_some() {
+            args.difficulty = config.difficulty;
+        }
+        if args.absurdle.is_none() && config.absurdle.is_some() {
+            args.absurdle = config.absurdle;
+        }
+        if args.daily.is_none() && config.daily.is_some() {
+            args.daily = config.daily;
+        }
+        if args.date.is_none() && config.date.is_some() {
+            args.date = config.date;
+        }
+        if args.epoch.is_none() && config.epoch.is_some() {
+            args.epoch = config.epoch;
+        }
+        if args.share.is_none() && config.share.is_some() {
+            args.share = config.share;
+        }
+        if args.share_ascii.is_none() && config.share_ascii.is_some() {
+            args.share_ascii = config.share_ascii;
+        }
+        if args.share_file.is_none() && config.share_file.is_some() {
+            args.share_file = config.share_file;
+        }
+        if args.challenge.is_none() && config.challenge.is_some() {
+            args.challenge = config.challenge;
+        }
+        if args.speedrun.is_none() && config.speedrun.is_some() {
+            args.speedrun = config.speedrun;
+        }
+        if args.time_attack.is_none() && config.time_attack.is_some() {
+            args.time_attack = config.time_attack;
+        }
+        if args.seed.is_none() && config.seed.is_some() {
+            args.seed = config.seed;
+        }
+        if args.day.is_none() && config.day.is_some() {
+            args.day = config.day;
+        }
+        if args.final_set.is_none() && config.final_set.is_some() {
+            args.final_set = config.final_set;
+        }
+        if args.acceptable_set.is_none() && config.acceptable_set.is_some() {
+            args.acceptable_set = config.acceptable_set;
+        }
+        if args.alphabet.is_none() && config.alphabet.is_some() {
+            args.alphabet = config.alphabet;
+        }
+        if args.stats.is_none() && config.stats.is_some() {
+            args.stats = config.stats;
+        }
+        if args.state.is_none() && config.state.is_some() {
+            args.state = config.state;
+        }
+        if args.keyboard_layout.is_none() && config.keyboard_layout.is_some() {
+            args.keyboard_layout = config.keyboard_layout;
+        }
+        if args.lang.is_none() && config.lang.is_some() {
+            args.lang = config.lang;
+        }
+        if args.length.is_none() {
+            args.length = config.length.or(Some(DEFAULT_LENGTH));
+        }
+        if args.max_guesses.is_none() {
+            args.max_guesses = config.max_guesses.or(Some(DEFAULT_LIMIT));
+        }
+        if args.boards.is_none() {
+            args.boards = config.boards.or(Some(DEFAULT_BOARDS));
+        }
+        let origin = |key: &str| match &file {
+            Some(file) if given[key].is_null() => file.origin(key),
+            _ => format!("--{}", key.replace('_', "-")),
+        };
+        let wrong = conflicts(&args, origin);
+        if !wrong.is_empty() {
+            let reason = format!("keys that don't go together:\n  {}", wrong.join("\n  "));
+            return Err(match &file {
+                Some(file) => Error::Config {
+                    path: file.path.clone(),
+                    line: None,
+                    key: None,
+                    reason,
+                },
+                None => Error::Args(reason),
+            });
+        }
+        if file.is_some() {
+            args
+        } else {
+            arg2config(arg)? // the same checks, with the defaults of the command line
+        }
+    };
+    let mut config = config;
+    if let Some(lang) = &config.lang {
+        lang.parse::<Lang>()?; // only a language given is checked, an unknown locale falls back to English
+    }
+    if let Some(code) = &config.challenge {
+        // the challenge sets the answers and the rules, checked against the word lists in wordsets
+        let challenge = Challenge::decode(code)?;
+        config.word = Some(challenge.answers().join(","));
+        config.length = Some(challenge.length());
+        config.boards = Some(challenge.answers().len());
+        config.difficulty = Some(level(&config).max(challenge.level())); // only made harder
+    }
+    Ok(config)
+}
+
+/**
+function: to set a challenge for the "challenge create" subcommand
+input: config: the ultimate Config struct from the arguments of the subcommand
+        dict: the word lists of the game
+output: the code of the challenge, a challenge error if no answer is given
+*/
+pub fn create(config: &Config, dict: &Dictionary) -> Result<String, Error> {
+    if config.word.is_none() {
+        return Err(Error::Challenge(String::from(
+            "no answer to set, give them with --word",
+        )));
+    } // never read from the input, it would be shown
+    let answers = word(&config.word, dict, config.boards.unwrap_or(DEFAULT_BOARDS))?;
+    Ok(Challenge::new(&answers, level(config), dict).code())
+}
+
+/**
+function: to build the word lists from the ultimate Config struct, and check the length, the day and the boards against them
+input: config: the ultimate Config struct
+output: the Dictionary of the game
+*/
+pub fn wordsets(config: &Config) -> Result<Dictionary, Error> {
+    let length = config.length.unwrap_or(DEFAULT_LENGTH);
+    if !(MIN_LENGTH..=MAX_LENGTH).contains(&length) {
+        return Err(Error::Args(format!(
+            "the word length {} is out of {} to {}",
+            length, MIN_LENGTH, MAX_LENGTH
+        )));
+    }
+    let dict = set(
+        &config.final_set,
+        &config.acceptable_set,
+        &config.alphabet,
+        length,
+    )?;
+    if let Some(day) = config.day {
+        if day > dict.finals().len() || day == 0 {
+            return Err(Error::Args(format!(
+                "the day {} is out of 1 to {}, the size of the final set",
+                day,
+                dict.finals().len()
+            )));
+        }
+    }
+    let boards = config.boards.unwrap_or(DEFAULT_BOARDS);
+    if boards == 0 || boards > MAX_BOARDS || boards > dict.finals().len() {
+        return Err(Error::Args(format!(
+            "{} boards are out of 1 to {}, or more than the final set has answers",
+            boards, MAX_BOARDS
+        )));
+    }
+    if config.absurdle == Some(true) && boards != 1 {
+        return Err(Error::Args(String::from(
+            "the absurdle mode is played on a single board",
+        ))); // the absurdle mode dodges on a single board
+    }
+    if let Some(code) = &config.challenge {
+        Challenge::decode(code)?.check(&dict)?;
+    }
+    Ok(dict)
+}
+
+/**
+function: to lay the alphabet of the word lists out on the keyboard chosen in the ultimate Config struct
+input: config: the ultimate Config struct
+        dict: the word lists of the game
+output: the rows of uppercase keys, QWERTY if no layout is chosen
+*/
+pub fn keys(config: &Config, dict: &Dictionary) -> Result<Vec<Vec<char>>, Error> {
+    let layout = match &config.keyboard_layout {
+        Some(text) => Layout::new(text)?,
+        None => Layout::qwerty(),
+    };
+    Ok(layout.rows(dict.alphabet()))
+}
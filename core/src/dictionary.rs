@@ -0,0 +1,110 @@
+use {
+    crate::{
+        alphabet::{lower, Alphabet},
+        challenge::fnv,
+    },
+    std::collections::HashSet,
+};
+
+/**
+the word lists of a game: the final set the answers are picked from and the
+acceptable set the guesses are checked against.
+the alphabet the words are spelled with goes along with them.
+the sorted lists keep the order the seeded shuffle relies on, the hash sets give
+O(1) lookups. it owns everything it holds, so it is Send + Sync and one instance
+can be shared between several games or solver threads.
+*/
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    finals: Vec<String>,  // sorted final set
+    accepts: Vec<String>, // sorted acceptable set
+    final_index: HashSet<String>,
+    accept_index: HashSet<String>,
+    alphabet: Alphabet,
+}
+
+impl Dictionary {
+    /**
+    function: to build a dictionary from the two word lists
+    input: finals: the sorted lowercase final set
+            accepts: the sorted lowercase acceptable set
+            alphabet: the letters the words are spelled with
+    output: the dictionary
+    */
+    pub fn new(finals: Vec<String>, accepts: Vec<String>, alphabet: Alphabet) -> Dictionary {
+        let final_index = finals.iter().cloned().collect();
+        let accept_index = accepts.iter().cloned().collect();
+        Dictionary {
+            finals,
+            accepts,
+            final_index,
+            accept_index,
+            alphabet,
+        }
+    }
+
+    /**
+    function: to get the final set
+    input: None
+    output: the words that can be picked as an answer, sorted
+    */
+    pub fn finals(&self) -> &[String] {
+        &self.finals
+    }
+
+    /**
+    function: to get the acceptable set
+    input: None
+    output: the words that can be guessed, sorted
+    */
+    pub fn accepts(&self) -> &[String] {
+        &self.accepts
+    }
+
+    /**
+    function: to check whether or not the word can be picked as an answer
+    input: word: the word in any case
+    output: true if it is in the final set
+    */
+    pub fn is_final(&self, word: &str) -> bool {
+        self.final_index.contains(&lower(word))
+    }
+
+    /**
+    function: to check whether or not the word can be guessed
+    input: word: the word in any case
+    output: true if it is in the acceptable set
+    */
+    pub fn is_acceptable(&self, word: &str) -> bool {
+        self.accept_index.contains(&lower(word))
+    }
+
+    /**
+    function: to get the alphabet of the word lists
+    input: None
+    output: the letters the words are spelled with
+    */
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+
+    /**
+    function: to tell the word lists apart, so that a challenge is played with the lists it was made with
+    input: None
+    output: the hash of the two lists and the alphabet
+    */
+    pub fn identity(&self) -> u32 {
+        let mut text = self.finals.join("\n");
+        text += "\n\n";
+        text += &self.accepts.join("\n");
+        text += "\n\n";
+        text.extend(self.alphabet.letters());
+        fnv(text.as_bytes())
+    }
+}
+
+// the dictionary is shared between threads, keep it Send + Sync
+const _: fn() = || {
+    fn shareable<T: Send + Sync>() {}
+    shareable::<Dictionary>();
+};
@@ -0,0 +1,140 @@
+use {std::io, thiserror::Error};
+
+/**
+the errors a game or the solver stops with, each one telling which file, line or word
+it was caused by where there is one.
+the programs print the message and exit with the code of its kind, so that a script
+calling them can tell the kinds apart:
+
+| code | kind                                                               |
+|------|--------------------------------------------------------------------|
+| 0    | no error                                                           |
+| 1    | `Io`, `Share`: the terminal or a file to write failed              |
+| 2    | `Args`: a bad argument or a bad combination of arguments           |
+| 3    | `Config`: the config file can't be read, a key or a value is wrong |
+| 4    | `WordList`: a word list or the alphabet is wrong, or used up       |
+| 5    | `State`: the state file can't be read or written                   |
+| 6    | `Challenge`: the challenge code is wrong                           |
+| 7    | `Played`: the daily puzzle asked for was played already            |
+| 8    | `Input`: the standard input ended or was not what was asked for    |
+| 9    | `Layout`: the keyboard layout is wrong                             |
+*/
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Io Error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Share Error: {path}: {source}")]
+    Share { path: String, source: io::Error },
+    #[error("Args Error: {0}")]
+    Args(String),
+    #[error("Config Error: {}", locate(.path, &None, .line, .key, .reason))]
+    Config {
+        path: String,
+        line: Option<usize>, // the line of the key, counted from 1
+        key: Option<String>, // the key of the config file that is wrong
+        reason: String,
+    },
+    #[error("Word List Error: {}", locate(.list, .path, .line, .word, .reason))]
+    WordList {
+        list: String,         // "final-set", "acceptable-set" or "alphabet"
+        path: Option<String>, // None for the built-in lists and the "--alphabet" argument
+        line: Option<usize>,  // counted from 1
+        word: Option<String>,
+        reason: String,
+    },
+    #[error("State Error: {path}: {reason}")]
+    State { path: String, reason: String },
+    #[error("Challenge Error: {0}")]
+    Challenge(String),
+    #[error("Daily Error: the puzzle of {date} was played")]
+    Played { date: String },
+    #[error("Input Error: {0}")]
+    Input(String),
+    #[error("Keyboard Layout Error: {}", locate("layout", .path, .line, &None, .reason))]
+    Layout {
+        path: Option<String>, // None for the presets
+        line: Option<usize>,
+        reason: String,
+    },
+}
+
+impl Error {
+    /**
+    function: to get the exit code of the error, listed in the doc of Error
+    input: None
+    output: the exit code, never 0
+    */
+    pub fn code(&self) -> i32 {
+        match self {
+            Error::Io(_) | Error::Share { .. } => 1,
+            Error::Args(_) => 2,
+            Error::Config { .. } => 3,
+            Error::WordList { .. } => 4,
+            Error::State { .. } => 5,
+            Error::Challenge(_) => 6,
+            Error::Played { .. } => 7,
+            Error::Input(_) => 8,
+            Error::Layout { .. } => 9,
+        }
+    }
+
+    /**
+    function: to build a word list error without a line or a word to point at
+    input: list: the name of the list
+            path: the file of the list, None for the built-in one
+            reason: what is wrong with it
+    output: the error
+    */
+    pub fn word_list(list: &str, path: &Option<String>, reason: String) -> Error {
+        Error::WordList {
+            list: String::from(list),
+            path: path.clone(),
+            line: None,
+            word: None,
+            reason,
+        }
+    }
+
+    /**
+    function: to build a state error
+    input: path: the "--state" argument
+            reason: what went wrong
+    output: the error
+    */
+    pub fn state(path: &Option<String>, reason: String) -> Error {
+        Error::State {
+            path: path.clone().unwrap_or_default(),
+            reason,
+        }
+    }
+}
+
+/**
+function: to write where an error was found, like "final-set words.txt:3: "abc1e" ..."
+input: name: the name of the file
+        path: the path of the file, None if there is no file
+        line: the line the error is on
+        word: the offending word
+        reason: what is wrong
+output: the message
+*/
+fn locate(
+    name: &str,
+    path: &Option<String>,
+    line: &Option<usize>,
+    word: &Option<String>,
+    reason: &str,
+) -> String {
+    let mut text = String::from(name);
+    if let Some(path) = path {
+        text += &format!(" {}", path);
+    }
+    if let Some(line) = line {
+        text += &format!(":{}", line);
+    }
+    text += ": ";
+    if let Some(word) = word {
+        text += &format!("\"{}\" ", word);
+    }
+    text + reason
+}
@@ -0,0 +1,276 @@
+use {
+    crate::error::Error,
+    std::{fmt, str::FromStr},
+};
+
+/**
+the color of a single letter of a guess.
+the order is the order the keyboard is updated in: a letter seen green never
+turns back to yellow, a letter seen yellow never turns back to red.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tile {
+    Red,    // the letter is not (or no more) in the answer
+    Yellow, // the letter is in the answer, but somewhere else
+    Green,  // the letter is in the right place
+}
+
+impl Tile {
+    /**
+    function: to get the letter the tile is written as in the "GYR" text
+    input: None
+    output: 'G', 'Y' or 'R'
+    */
+    pub fn to_char(self) -> char {
+        match self {
+            Tile::Green => 'G',
+            Tile::Yellow => 'Y',
+            Tile::Red => 'R',
+        }
+    }
+
+    /**
+    function: to read a tile from its letter in the "GYR" text
+    input: letter: the color letter
+    output: the tile, None for any other letter
+    */
+    pub fn from_char(letter: char) -> Option<Tile> {
+        match letter {
+            'G' => Some(Tile::Green),
+            'Y' => Some(Tile::Yellow),
+            'R' => Some(Tile::Red),
+            _ => None,
+        }
+    }
+
+    // the base-3 digit of the tile in a pattern code
+    fn digit(self) -> u32 {
+        match self {
+            Tile::Red => 0,
+            Tile::Yellow => 1,
+            Tile::Green => 2,
+        }
+    }
+
+    fn from_digit(digit: u32) -> Tile {
+        match digit {
+            0 => Tile::Red,
+            1 => Tile::Yellow,
+            _ => Tile::Green,
+        }
+    }
+}
+
+/**
+function: to get the letter a key of the keyboard is shown as
+input: key: the best tile the letter has got, None if it has not been guessed
+output: 'G', 'Y', 'R', or 'X' for a letter not guessed yet
+*/
+pub fn key_char(key: Option<Tile>) -> char {
+    match key {
+        Some(tile) => tile.to_char(),
+        None => 'X',
+    }
+}
+
+/**
+function: to update a key of the keyboard with a new tile, keeping the best one
+input: key: the key to be updated
+        tile: the tile the letter got in the new guess
+output: None
+*/
+pub fn mark(key: &mut Option<Tile>, tile: Tile) {
+    if Some(tile) > *key {
+        *key = Some(tile);
+    }
+}
+
+// the longest pattern whose code still fits in a u32
+pub const MAX_TILES: usize = 20;
+
+/**
+the colors of a whole guess, packed into a base-3 code where the first letter
+is the lowest digit. it is Copy and never allocates, so patterns can be compared,
+hashed or used to index a bucket array directly.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Feedback {
+    code: u32,
+    length: usize,
+}
+
+impl Feedback {
+    /**
+    function: to build a pattern from its tiles
+    input: tiles: the tiles of each letter
+    output: the pattern, None if there are more than MAX_TILES tiles
+    */
+    pub fn from_tiles(tiles: &[Tile]) -> Option<Feedback> {
+        if tiles.len() > MAX_TILES {
+            return None;
+        }
+        let mut code = 0;
+        for tile in tiles.iter().rev() {
+            code = code * 3 + tile.digit();
+        }
+        Some(Feedback {
+            code,
+            length: tiles.len(),
+        })
+    }
+
+    /**
+    function: to rebuild a pattern from its code
+    input: code: the base-3 code
+            length: the word length of the pattern
+    output: the pattern, None if the code doesn't fit the length
+    */
+    pub fn from_code(code: u32, length: usize) -> Option<Feedback> {
+        if length > MAX_TILES || code >= Feedback::patterns(length) {
+            return None;
+        }
+        Some(Feedback { code, length })
+    }
+
+    /**
+    function: to count the different patterns of a word length
+    input: length: the word length
+    output: 3 to the power of length, the size of a bucket array indexed by code
+    */
+    pub fn patterns(length: usize) -> u32 {
+        3u32.pow(length as u32)
+    }
+
+    /**
+    function: to get the base-3 code of the pattern
+    input: None
+    output: the code, below `Feedback::patterns(self.len())`
+    */
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /**
+    function: to get the word length of the pattern
+    input: None
+    output: the number of tiles
+    */
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /**
+    function: to check whether the pattern has no tile
+    input: None
+    output: true for the pattern of an empty word
+    */
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /**
+    function: to get the tile of a letter
+    input: num: the order of the letter in the guess
+    output: the tile of the letter
+    */
+    pub fn tile(&self, num: usize) -> Tile {
+        assert!(num < self.length, "tile out of the pattern");
+        Tile::from_digit(self.code / 3u32.pow(num as u32) % 3)
+    }
+
+    /**
+    function: to walk through the tiles of the pattern
+    input: None
+    output: the tiles from the first letter to the last
+    */
+    pub fn tiles(&self) -> impl Iterator<Item = Tile> + '_ {
+        (0..self.length).map(move |num| self.tile(num))
+    }
+
+    /**
+    function: to check whether the guess is the answer
+    input: None
+    output: true if every tile is green
+    */
+    pub fn is_win(&self) -> bool {
+        self.code == Feedback::patterns(self.length) - 1
+    }
+}
+
+// written as the "GRYRR" text of the non interactive output
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for tile in self.tiles() {
+            write!(f, "{}", tile.to_char())?;
+        }
+        Ok(())
+    }
+}
+
+// read from the "GRYRR" text
+impl FromStr for Feedback {
+    type Err = Error;
+    fn from_str(text: &str) -> Result<Feedback, Error> {
+        let mut tiles = vec![];
+        for letter in text.chars() {
+            match Tile::from_char(letter) {
+                Some(tile) => tiles.push(tile),
+                None => {
+                    return Err(Error::Input(format!(
+                        "{} is not a pattern of G, Y and R",
+                        text
+                    )))
+                }
+            }
+        }
+        Feedback::from_tiles(&tiles)
+            .ok_or_else(|| Error::Input(format!("{} is longer than a pattern can be", text)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_round_trip() {
+        let tiles = [Tile::Green, Tile::Red, Tile::Yellow, Tile::Red, Tile::Red];
+        let feedback = Feedback::from_tiles(&tiles).unwrap();
+        assert_eq!(feedback.code(), 2 + 9); // the first letter is the lowest digit, the yellow third is 1 * 3^2
+        assert_eq!(Feedback::from_code(feedback.code(), 5), Some(feedback));
+        assert_eq!(feedback.tiles().collect::<Vec<Tile>>(), tiles);
+        for code in 0..Feedback::patterns(3) {
+            let feedback = Feedback::from_code(code, 3).unwrap();
+            let tiles: Vec<Tile> = feedback.tiles().collect();
+            assert_eq!(Feedback::from_tiles(&tiles).unwrap().code(), code);
+        }
+    }
+
+    #[test]
+    fn code_out_of_range() {
+        assert_eq!(Feedback::from_code(Feedback::patterns(5), 5), None);
+        assert_eq!(Feedback::from_code(0, MAX_TILES + 1), None);
+        assert_eq!(Feedback::from_tiles(&[Tile::Red; MAX_TILES + 1]), None);
+        assert!(Feedback::from_tiles(&[Tile::Green; MAX_TILES])
+            .unwrap()
+            .is_win());
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let feedback: Feedback = "GRYRR".parse().unwrap();
+        assert_eq!(feedback.to_string(), "GRYRR");
+        assert_eq!(feedback.len(), 5);
+        assert!(!feedback.is_win());
+        assert!("GGGGG".parse::<Feedback>().unwrap().is_win());
+        assert!("".parse::<Feedback>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn bad_text() {
+        assert!("GRXRR".parse::<Feedback>().is_err());
+        assert!("grYRR".parse::<Feedback>().is_err()); // the letters are upper case
+        assert!("GR RR".parse::<Feedback>().is_err());
+        assert!("R".repeat(MAX_TILES + 1).parse::<Feedback>().is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    alphabet::{lower, upper_letter, Alphabet},
+    error::Error,
+};
+
+/**
+the rows of keys the interactive keyboards are drawn with.
+a layout may hold letters out of the alphabet of the game, they are left out; the
+letters of the alphabet it misses are put in extra rows below it.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    rows: Vec<Vec<char>>, // lowercase
+}
+
+// the layouts that can be chosen by name
+const PRESETS: [(&str, &[&str]); 5] = [
+    ("qwerty", &["qwertyuiop", "asdfghjkl", "zxcvbnm"]),
+    ("azerty", &["azertyuiop", "qsdfghjklm", "wxcvbn"]),
+    ("qwertz", &["qwertzuiopü", "asdfghjklöä", "yxcvbnm"]),
+    ("dvorak", &["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"]),
+    ("colemak", &["qwfpgjluy", "arstdhneio", "zxcvbkm"]),
+];
+
+// the most keys in an extra row of the keyboard
+const ROW_KEYS: usize = 10;
+
+impl Layout {
+    /**
+    function: to get the QWERTY layout, the default one
+    input: None
+    output: the layout
+    */
+    pub fn qwerty() -> Layout {
+        Layout::from_rows(PRESETS[0].1.iter().copied(), None).unwrap()
+    }
+
+    /**
+    function: to read a layout from the "--keyboard-layout" argument
+    input: text: the name of a preset ("qwerty", "azerty", "qwertz", "dvorak", "colemak"),
+            or the path of a layout file, each line of which is a row of keys
+    output: the layout, a layout error if the file can't be read or a key appears twice
+    */
+    pub fn new(text: &str) -> Result<Layout, Error> {
+        match PRESETS.iter().find(|(name, _)| *name == lower(text)) {
+            Some((_, rows)) => Layout::from_rows(rows.iter().copied(), None),
+            None => match std::fs::read_to_string(text) {
+                Ok(file) => Layout::from_rows(file.lines(), Some(text)),
+                Err(err) => Err(Error::Layout {
+                    path: Some(String::from(text)),
+                    line: None,
+                    reason: format!("not a preset and can't be read ({})", err),
+                }),
+            },
+        }
+    }
+
+    /**
+    function: to build a layout from its rows, the characters other than letters are skipped
+    input: rows: the text of each row
+            path: the file the rows are read from, None for a preset
+    output: the layout, a layout error if a key appears twice or there is no key
+    */
+    fn from_rows<'a>(
+        rows: impl Iterator<Item = &'a str>,
+        path: Option<&str>,
+    ) -> Result<Layout, Error> {
+        let mut layout: Vec<Vec<char>> = vec![];
+        for (line, row) in rows.enumerate() {
+            let mut keys = vec![];
+            for key in lower(row).chars().filter(|key| key.is_alphabetic()) {
+                if keys.contains(&key) || layout.iter().any(|row| row.contains(&key)) {
+                    return Err(Error::Layout {
+                        path: path.map(String::from),
+                        line: Some(line + 1),
+                        reason: format!("the key {} appears twice", upper_letter(key)),
+                    });
+                }
+                keys.push(key);
+            }
+            if !keys.is_empty() {
+                layout.push(keys);
+            }
+        }
+        if layout.is_empty() {
+            return Err(Error::Layout {
+                path: path.map(String::from),
+                line: None,
+                reason: String::from("has no key"),
+            });
+        }
+        Ok(Layout { rows: layout })
+    }
+
+    /**
+    function: to lay the letters of an alphabet out on the keyboard
+    input: alphabet: the alphabet of the word lists
+    output: the rows of uppercase keys
+    */
+    pub fn rows(&self, alphabet: &Alphabet) -> Vec<Vec<char>> {
+        let mut rows: Vec<Vec<char>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .copied()
+                    .filter(|key| alphabet.contains(*key))
+                    .collect()
+            })
+            .collect();
+        let rest: Vec<char> = alphabet
+            .letters()
+            .iter()
+            .copied()
+            .filter(|letter| !self.rows.iter().any(|row| row.contains(letter)))
+            .collect();
+        rows.extend(rest.chunks(ROW_KEYS).map(|row| row.to_vec()));
+        rows.retain(|row| !row.is_empty());
+        rows.iter()
+            .map(|row| row.iter().map(|key| upper_letter(*key)).collect())
+            .collect()
+    }
+}
@@ -0,0 +1,68 @@
+//! The shared core of the wordle game.
+//!
+//! Every frontend (the `wordle` command line game, the `tui` interface and the
+//! `tips` solver) links against this crate, so that the rules of the game, the
+//! word lists, the command line and config handling and the json state file
+//! only live in one place.
+//!
+//! The items re-exported at the top of the crate are its API:
+//!
+//! * [`Dictionary`] holds the word lists a game is played with, spelled with an
+//!   [`Alphabet`].
+//! * [`color`] gives the [`Feedback`] of a guess, a [`Tile`] for each letter packed
+//!   into a base-3 code and written as the "GRYRR" text.
+//! * [`fits`] checks a guess against the [`Difficulty`], telling the [`Rule`] it breaks.
+//! * [`check`], [`expected_entropy`] and [`max_entropy`] are the search the `tips`
+//!   solver and `wordle solve` suggest guesses with, narrowing the answers down and
+//!   weighing the guesses by entropy.
+//! * [`stats`] counts the [`Stats`] of the rounds played.
+//! * [`stateload`] and [`statesave`] read and write the json state file, a
+//!   [`Content`] of a [`Round`] for every game, each with its [`Details`].
+//! * [`Challenge`] is the code one player sets for the others.
+//! * [`Layout`] is the keyboard the interactive keyboards are drawn with.
+//! * [`Lang`] and the [`Msg`] catalog write the interactive messages in English or Chinese.
+//! * [`Error`] is what the fallible functions return, each kind of which the
+//!   programs exit with a code of its own.
+//!
+//! The modules `common` and `settings` also hold the arguments, the config files
+//! and the game loop helpers the three programs share. They are not part of the
+//! API, and are left out of these docs.
+
+pub mod alphabet;
+pub mod builtin_words;
+pub mod challenge;
+#[doc(hidden)]
+pub mod common; // the plumbing of the three programs, the API is re-exported below
+pub mod dictionary;
+pub mod error;
+pub mod feedback;
+pub mod layout;
+pub mod locale;
+#[doc(hidden)]
+pub mod settings; // the config files of the three programs
+pub mod solver;
+
+/// The letters a word list is spelled with, and how they are folded to upper case.
+pub use alphabet::Alphabet;
+/// A round one player sets for the others, shared as an opaque code.
+pub use challenge::Challenge;
+/// The feedback of a guess against an answer.
+pub use common::color;
+/// Whether a guess keeps to a difficulty, and the rule it breaks if not.
+pub use common::{fits, Difficulty, Rule};
+/// The state file: what it keeps of every round, and how it is read and written.
+pub use common::{stateload, statesave, Content, Details, Mode, Round, Unfinished, STATE_VERSION};
+/// The statistics of the rounds played, from the guesses and the results kept.
+pub use common::{stats, Outcome, Stats};
+/// The final set the answers are picked from and the acceptable set of guesses.
+pub use dictionary::Dictionary;
+/// The error of the fallible functions, with the code the programs exit with.
+pub use error::Error;
+/// The color of a letter, and the feedback of a whole guess packed into a base-3 code.
+pub use feedback::{Feedback, Tile};
+/// A keyboard layout the interactive keyboards are drawn with.
+pub use layout::Layout;
+/// The language of the interactive messages, and the catalog they are written from.
+pub use locale::{Lang, Msg};
+/// The answers left by the clues, and the guesses expected to give the most information.
+pub use solver::{check, expected_entropy, max_entropy};
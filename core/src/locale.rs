@@ -0,0 +1,448 @@
+use {
+    crate::{
+        common::{Difficulty, Rule},
+        error::Error,
+    },
+    std::{env, str::FromStr},
+};
+
+/**
+the languages the messages of the interactive terminal and the tui are written in,
+chosen by "--lang", the "lang" key of the config file or the locale of the environment.
+the output of a non interactive terminal is a protocol and is never translated.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    En,
+    Zh,
+}
+
+// read from "en", "zh" or a locale like "zh_CN.UTF-8"
+impl FromStr for Lang {
+    type Err = Error;
+    fn from_str(text: &str) -> Result<Lang, Error> {
+        let language = text
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "en" | "c" | "posix" => Ok(Lang::En),
+            "zh" => Ok(Lang::Zh),
+            _ => Err(Error::Args(format!(
+                "the language {} is not en or zh",
+                text
+            ))),
+        }
+    }
+}
+
+impl Lang {
+    /**
+    function: to choose the language of the messages
+    input: lang: the language given with "--lang" or in the config file, checked by config
+    output: the language given, or the one of $LC_ALL, $LC_MESSAGES or $LANG, English if none is known
+    */
+    pub fn choose(lang: &Option<String>) -> Lang {
+        if let Some(lang) = lang {
+            return lang.parse().unwrap_or_default();
+        }
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|locale| !locale.is_empty()) // the first one set wins, as in gettext
+            .and_then(|locale| locale.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /**
+    function: to write a message in the language
+    input: msg: the message
+    output: the text
+    */
+    pub fn text(self, msg: Msg) -> String {
+        match self {
+            Lang::En => msg.en(),
+            Lang::Zh => msg.zh(),
+        }
+    }
+}
+
+/**
+the messages of the interactive terminal and the tui, with what they are built from.
+*/
+pub enum Msg<'a> {
+    InputGuess,
+    InputAnswer,
+    NotInList,
+    Invalid(&'a str),               // the reason
+    Rule(Rule),                     // the reason a guess breaks the difficulty
+    InvalidGuess(&'a str, &'a str), // the guess and the reason
+    Correct(usize, &'a str),        // the guesses and the time
+    TimeUp(&'a str),                // the answers
+    Failed(&'a str),                // the answers
+    AnotherRound,
+    LeftRound(&'a [String]), // the guesses of the round left in the middle
+    ContinueRound,
+    Summary(usize, usize, f64), // the rounds won, the rounds lost and the average guesses to win
+    Streaks(usize, f64, usize, usize), // played, win %, the current and the max streak
+    Times(&'a str, &'a str),    // the best time and the average time
+    FrequentWords,
+    WordUsed(&'a str, i32), // the word and the times it was used
+    Distribution,
+    SpeedrunOver(usize, usize, &'a str), // solved, rounds and the time
+    TimeAttackOver(usize, u64),          // solved and the minutes
+    NewStateFile,
+    Mode(Difficulty),
+    AbsurdleMode,
+    DailyOf(&'a str), // the date
+    ContinuedMode,
+    Results(usize, usize, f64, f64, usize, usize), // won, lost, win %, average guesses, the streaks
+    BestTime(&'a str, &'a str),                    // the best time and the average time
+    SharedTo(&'a str),                             // the file
+    SharedOnExit,
+    Offer(&'a [String]), // the guesses of the round left in the middle
+    UnfinishedTitle,
+    CorrectShort,
+    FailAt(&'a str), // the answers
+    ShareOrExit,
+    NewGame,
+    Accepted,
+    CorrectAgain,
+    FailAtAgain(&'a str), // the answers
+    AnotherOrShare,
+    HistoryTitle(&'a str), // the time
+    InformationTitle,
+    KeyboardTitle,
+    GuessTitle(usize),
+    MessageTitle,
+    ModeTitle,
+    StatisticTitle,
+    WordsTitle,
+    DistributionTitle,
+    Candidates(usize, &'a str), // the count of the possible answers and the ones shown
+    Suggested(&'a str, f64),    // the guess and the bits it is expected to give
+    SuggestedFirst,             // the title of the first guesses the tips suggest
+    PossibleAnswers,            // the title of the answers the tips have left
+    FinalSet(usize),            // the words of the final set
+    AcceptableSet(usize),       // the words of the acceptable set
+    Alphabet(&'a str),
+    Identity(u32),      // the identity of the word lists
+    Solved(usize),      // the guesses
+    AnswerWas(&'a str), // the answers
+}
+
+/**
+function: to choose the English form of a noun for a count
+input: count: the count
+        one: the singular
+        other: the plural
+output: the singular for exactly one, the plural otherwise, zero included
+*/
+fn plural<'a>(count: usize, one: &'a str, other: &'a str) -> &'a str {
+    if count == 1 {
+        one
+    } else {
+        other
+    }
+}
+
+/**
+function: to get the ordinal of a letter's position, as in "3rd letter"
+input: num: the order of the letter in the word, from 0
+output: the ordinal text
+*/
+fn ordinal(num: usize) -> String {
+    let num = num + 1;
+    let suffix = match (num % 10, num % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", num, suffix)
+}
+
+/**
+function: to tell how many of a letter a guess needs
+input: letter: the letter
+        count: the number of the letter
+output: "E" for one of it, "2 E's" for more
+*/
+fn letters(letter: char, count: usize) -> String {
+    if count == 1 {
+        letter.to_string()
+    } else {
+        format!("{} {}'s", count, letter)
+    }
+}
+
+impl Msg<'_> {
+    /**
+    function: to write the message in English
+    input: None
+    output: the text
+    */
+    fn en(&self) -> String {
+        match self {
+            Msg::InputGuess => String::from("Input your guess: "),
+            Msg::InputAnswer => String::from("Input the answer:"),
+            Msg::NotInList => String::from("not in the word list"),
+            Msg::Invalid(reason) => format!("INVALID: {}", reason),
+            Msg::Rule(Rule::MustBe(num, letter)) => {
+                format!("{} letter must be {}", ordinal(*num), letter)
+            }
+            Msg::Rule(Rule::MustContain(letter, count)) => {
+                format!("guess must contain {}", letters(*letter, *count))
+            }
+            Msg::Rule(Rule::CantBe(num, letter)) => {
+                format!("{} letter can't be {}", ordinal(*num), letter)
+            }
+            Msg::Rule(Rule::CantContain(letter)) => format!("guess can't contain {}", letter),
+            Msg::Rule(Rule::TooMany(letter, count)) => {
+                format!("guess can't contain more than {}", letters(*letter, *count))
+            }
+            Msg::InvalidGuess(guess, reason) => format!("{}: INVALID, {}", guess, reason),
+            Msg::Correct(total, time) => format!("CORRECT {} in {}", total, time),
+            Msg::TimeUp(answers) => format!("TIME UP, {}", answers),
+            Msg::Failed(answers) => format!("FAILED, {}", answers),
+            Msg::AnotherRound => String::from(
+                "Another round? (Input 'Y' to start another round, 'N' to end the game.)",
+            ),
+            Msg::LeftRound(guesses) => format!(
+                "You left a round after {} {}: {}",
+                guesses.len(),
+                plural(guesses.len(), "guess", "guesses"),
+                guesses.join(" ")
+            ),
+            Msg::ContinueRound => {
+                String::from("Continue it? (Input 'Y' to continue the round, 'N' to drop it.)")
+            }
+            Msg::Summary(wins, losses, average) => format!(
+                "Up to now, you have won {} {}, lost {} {}, and tried {:.2} times on average to win a round.",
+                wins,
+                plural(*wins, "round", "rounds"),
+                losses,
+                plural(*losses, "round", "rounds"),
+                average // a fraction is always plural, "1.00 times"
+            ),
+            Msg::Streaks(played, rate, current, max) => format!(
+                "Played: {}   Win %: {:.0}   Current streak: {}   Max streak: {}",
+                played, rate, current, max
+            ),
+            Msg::Times(best, average) => {
+                format!("Your best time is {}, and {} on average.", best, average)
+            }
+            Msg::FrequentWords => {
+                String::from("These are the words you have used relatively more frequently: ")
+            }
+            Msg::WordUsed(word, times) => {
+                format!("{},{} {}", word, times, plural(*times as usize, "time", "times"))
+            }
+            Msg::Distribution => String::from("Guess distribution:"),
+            Msg::SpeedrunOver(solved, rounds, time) => format!(
+                "Speedrun over: {} of {} solved in {}",
+                solved, rounds, time
+            ),
+            Msg::TimeAttackOver(solved, minutes) => format!(
+                "Time attack over: {} solved in {} {}",
+                solved,
+                minutes,
+                plural(*minutes as usize, "minute", "minutes")
+            ),
+            Msg::NewStateFile => String::from("A new state file is started. "),
+            Msg::Mode(level) => String::from(match level {
+                Difficulty::Normal => "Simple mode",
+                Difficulty::Hard => "Difficult mode",
+                Difficulty::Strict => "Strict mode",
+            }),
+            Msg::AbsurdleMode => String::from(", Absurdle mode"),
+            Msg::DailyOf(date) => format!(", Daily puzzle of {}", date),
+            Msg::ContinuedMode => String::from(", Continued"),
+            Msg::Results(wins, losses, rate, average, current, max) => format!(
+                "win: {} / lose: {} ({:.0}%)    average trail when winning: {:.2}    streak: {} / max: {}",
+                wins, losses, rate, average, current, max
+            ),
+            Msg::BestTime(best, average) => {
+                format!("    best time: {} / average time: {}", best, average)
+            }
+            Msg::SharedTo(file) => format!("Shared to {}. ", file),
+            Msg::SharedOnExit => String::from("Shared, shown when the game exits. "),
+            Msg::Offer(guesses) => format!(
+                "You left a round after {}. Input 'Y' to continue it, 'N' to drop it.",
+                guesses.join(" ")
+            ),
+            Msg::UnfinishedTitle => String::from("Unfinished round"),
+            Msg::CorrectShort => String::from("Correct! "),
+            Msg::FailAt(answers) => format!("Fail at {}. ", answers),
+            Msg::ShareOrExit => String::from("Input 'S' to share, click 'ESC' to exit"),
+            Msg::NewGame => String::from("New Game On"),
+            Msg::Accepted => String::from("ACCEPTED"),
+            Msg::CorrectAgain => {
+                String::from("Correct! Input 'Y' to play again, click 'ESC' to exit.")
+            }
+            Msg::FailAtAgain(answers) => format!(
+                "Fail at {}. Input 'Y' to play again, click 'ESC' to exit.",
+                answers
+            ),
+            Msg::AnotherOrShare => {
+                String::from("Input 'Y' for another game, 'S' to share, click 'ESC' to exit")
+            }
+            Msg::HistoryTitle(time) => format!("History  {}", time),
+            Msg::InformationTitle => String::from("information"),
+            Msg::KeyboardTitle => String::from("Keyboard"),
+            Msg::GuessTitle(number) => format!("guess {}", number),
+            Msg::MessageTitle => String::from("message"),
+            Msg::ModeTitle => String::from("mode"),
+            Msg::StatisticTitle => String::from("statistic"),
+            Msg::WordsTitle => String::from("words preferred"),
+            Msg::DistributionTitle => String::from("guess distribution"),
+            Msg::Candidates(count, shown) => format!(
+                "{} possible {}: {}",
+                count,
+                plural(*count, "answer", "answers"),
+                shown
+            ),
+            Msg::Suggested(guess, bits) => {
+                format!("Suggested guess: {} ({:.2} bits)", guess, bits)
+            }
+            Msg::SuggestedFirst => String::from("Suggested guesses: "),
+            Msg::PossibleAnswers => String::from("possible answers are as follows:"),
+            Msg::FinalSet(count) => {
+                format!("Final set: {} {}", count, plural(*count, "word", "words"))
+            }
+            Msg::AcceptableSet(count) => format!(
+                "Acceptable set: {} {}",
+                count,
+                plural(*count, "word", "words")
+            ),
+            Msg::Alphabet(letters) => format!("Alphabet: {}", letters),
+            Msg::Identity(identity) => format!(
+                "Identity: {:08x}, the same for everyone with these word lists",
+                identity
+            ),
+            Msg::Solved(total) => format!(
+                "Solved in {} {}",
+                total,
+                plural(*total, "guess", "guesses")
+            ),
+            Msg::AnswerWas(answers) => format!("Failed, the answer was {}", answers),
+        }
+    }
+
+    /**
+    function: to write the message in Chinese, where nouns have no plural
+    input: None
+    output: the text
+    */
+    fn zh(&self) -> String {
+        match self {
+            Msg::InputGuess => String::from("请输入你的猜测："),
+            Msg::InputAnswer => String::from("请输入答案："),
+            Msg::NotInList => String::from("不在词表中"),
+            Msg::Invalid(reason) => format!("无效：{}", reason),
+            Msg::Rule(Rule::MustBe(num, letter)) => {
+                format!("第 {} 个字母必须是 {}", num + 1, letter)
+            }
+            Msg::Rule(Rule::MustContain(letter, 1)) => format!("猜测必须包含 {}", letter),
+            Msg::Rule(Rule::MustContain(letter, count)) => {
+                format!("猜测必须包含 {} 个 {}", count, letter)
+            }
+            Msg::Rule(Rule::CantBe(num, letter)) => {
+                format!("第 {} 个字母不能是 {}", num + 1, letter)
+            }
+            Msg::Rule(Rule::CantContain(letter)) => format!("猜测不能包含 {}", letter),
+            Msg::Rule(Rule::TooMany(letter, count)) => {
+                format!("猜测最多只能包含 {} 个 {}", count, letter)
+            }
+            Msg::InvalidGuess(guess, reason) => format!("{}：无效，{}", guess, reason),
+            Msg::Correct(total, time) => format!("正确！猜了 {} 次，用时 {}", total, time),
+            Msg::TimeUp(answers) => format!("时间到，答案是 {}", answers),
+            Msg::Failed(answers) => format!("失败，答案是 {}", answers),
+            Msg::AnotherRound => {
+                String::from("再来一局？（输入 'Y' 开始新的一局，输入 'N' 结束游戏。）")
+            }
+            Msg::LeftRound(guesses) => format!(
+                "你有一局在猜了 {} 次后离开了：{}",
+                guesses.len(),
+                guesses.join(" ")
+            ),
+            Msg::ContinueRound => String::from("继续这一局吗？（输入 'Y' 继续，输入 'N' 放弃。）"),
+            Msg::Summary(wins, losses, average) => format!(
+                "到目前为止，你赢了 {} 局，输了 {} 局，平均猜 {:.2} 次赢下一局。",
+                wins, losses, average
+            ),
+            Msg::Streaks(played, rate, current, max) => format!(
+                "已玩：{}   胜率 %：{:.0}   当前连胜：{}   最长连胜：{}",
+                played, rate, current, max
+            ),
+            Msg::Times(best, average) => format!("你的最佳用时是 {}，平均用时 {}。", best, average),
+            Msg::FrequentWords => String::from("这些是你相对用得最多的词："),
+            Msg::WordUsed(word, times) => format!("{}，{} 次", word, times),
+            Msg::Distribution => String::from("猜测次数分布："),
+            Msg::SpeedrunOver(solved, rounds, time) => {
+                format!("竞速结束：{} 局中解出 {} 局，用时 {}", rounds, solved, time)
+            }
+            Msg::TimeAttackOver(solved, minutes) => {
+                format!("限时挑战结束：{} 分钟内解出 {} 局", minutes, solved)
+            }
+            Msg::NewStateFile => String::from("已新建状态文件。"),
+            Msg::Mode(level) => String::from(match level {
+                Difficulty::Normal => "普通模式",
+                Difficulty::Hard => "困难模式",
+                Difficulty::Strict => "严格模式",
+            }),
+            Msg::AbsurdleMode => String::from("，Absurdle 模式"),
+            Msg::DailyOf(date) => format!("，{} 的每日谜题", date),
+            Msg::ContinuedMode => String::from("，继续的一局"),
+            Msg::Results(wins, losses, rate, average, current, max) => format!(
+                "胜：{} / 负：{}（{:.0}%）    获胜平均猜测次数：{:.2}    连胜：{} / 最长：{}",
+                wins, losses, rate, average, current, max
+            ),
+            Msg::BestTime(best, average) => {
+                format!("    最佳用时：{} / 平均用时：{}", best, average)
+            }
+            Msg::SharedTo(file) => format!("已分享到 {}。", file),
+            Msg::SharedOnExit => String::from("已分享，退出游戏后显示。"),
+            Msg::Offer(guesses) => format!(
+                "你有一局在猜了 {} 后离开了。输入 'Y' 继续，输入 'N' 放弃。",
+                guesses.join(" ")
+            ),
+            Msg::UnfinishedTitle => String::from("未完成的一局"),
+            Msg::CorrectShort => String::from("正确！"),
+            Msg::FailAt(answers) => format!("失败，答案是 {}。", answers),
+            Msg::ShareOrExit => String::from("输入 'S' 分享，按 'ESC' 退出"),
+            Msg::NewGame => String::from("新的一局开始"),
+            Msg::Accepted => String::from("有效"),
+            Msg::CorrectAgain => String::from("正确！输入 'Y' 再玩一局，按 'ESC' 退出。"),
+            Msg::FailAtAgain(answers) => format!(
+                "失败，答案是 {}。输入 'Y' 再玩一局，按 'ESC' 退出。",
+                answers
+            ),
+            Msg::AnotherOrShare => String::from("输入 'Y' 再玩一局，输入 'S' 分享，按 'ESC' 退出"),
+            Msg::HistoryTitle(time) => format!("历史  {}", time),
+            Msg::InformationTitle => String::from("信息"),
+            Msg::KeyboardTitle => String::from("键盘"),
+            Msg::GuessTitle(number) => format!("第 {} 次猜测", number),
+            Msg::MessageTitle => String::from("消息"),
+            Msg::ModeTitle => String::from("模式"),
+            Msg::StatisticTitle => String::from("统计"),
+            Msg::WordsTitle => String::from("常用词"),
+            Msg::DistributionTitle => String::from("猜测次数分布"),
+            Msg::Candidates(count, shown) => format!("可能的答案有 {} 个：{}", count, shown),
+            Msg::Suggested(guess, bits) => format!("建议猜测：{}（{:.2} 比特）", guess, bits),
+            Msg::SuggestedFirst => String::from("建议的猜测："),
+            Msg::PossibleAnswers => String::from("可能的答案如下："),
+            Msg::FinalSet(count) => format!("答案词表：{} 个词", count),
+            Msg::AcceptableSet(count) => format!("可猜词表：{} 个词", count),
+            Msg::Alphabet(letters) => format!("字母表：{}", letters),
+            Msg::Identity(identity) => {
+                format!("标识：{:08x}，词表相同的人都一样", identity)
+            }
+            Msg::Solved(total) => format!("猜了 {} 次解出", total),
+            Msg::AnswerWas(answers) => format!("失败，答案是 {}", answers),
+        }
+    }
+}
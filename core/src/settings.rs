@@ -0,0 +1,597 @@
+use {
+    crate::{
+        common::{fread, Config},
+        error::Error,
+        locale::Lang,
+    },
+    serde_json::{json, Map, Value},
+    std::{
+        collections::HashMap,
+        env,
+        path::{Path, PathBuf},
+    },
+};
+
+// the line every key of a table is set on, counted from 1
+type Lines = HashMap<String, usize>;
+
+// the line, the key and the reason of a key that is wrong
+type Wrong = (Option<usize>, String, String);
+
+/**
+the keys a config file takes, the fields of Config, and the type each one expects.
+*/
+pub const KEYS: [(&str, &str); 26] = [
+    ("word", "a string of the answers separated by ','"),
+    ("random", "a boolean"),
+    ("seed", "a whole number"),
+    ("day", "a whole number"),
+    ("difficult", "a boolean"),
+    ("difficulty", "one of \"normal\", \"hard\" and \"strict\""),
+    ("stats", "a boolean"),
+    ("final_set", "a string, the path of a file"),
+    ("acceptable_set", "a string, the path of a file"),
+    ("alphabet", "a string"),
+    ("state", "a string, the path of a file"),
+    ("keyboard_layout", "a string"),
+    ("length", "a whole number"),
+    ("max_guesses", "a whole number, 0 for unlimited"),
+    ("boards", "a whole number"),
+    ("absurdle", "a boolean"),
+    ("daily", "a boolean"),
+    ("date", "a date, YYYY-MM-DD"),
+    ("epoch", "a date, YYYY-MM-DD"),
+    ("share", "a boolean"),
+    ("share_ascii", "a boolean"),
+    ("share_file", "a string, the path of a file"),
+    ("challenge", "a string"),
+    ("speedrun", "a whole number above 0"),
+    ("time_attack", "a whole number above 0"),
+    ("lang", "\"en\", \"zh\" or a locale like \"zh_CN.UTF-8\""),
+];
+
+/**
+the pairs of keys that can't be set together, whether in the file, on the command line or one in each.
+*/
+pub const CONFLICTS: [(&str, &str); 26] = [
+    ("random", "word"),
+    ("absurdle", "random"),
+    ("absurdle", "word"),
+    ("daily", "random"),
+    ("daily", "word"),
+    ("daily", "day"),
+    ("daily", "absurdle"),
+    ("date", "random"),
+    ("date", "word"),
+    ("date", "day"),
+    ("date", "absurdle"),
+    ("speedrun", "time_attack"),
+    ("speedrun", "random"),
+    ("speedrun", "word"),
+    ("speedrun", "daily"),
+    ("speedrun", "date"),
+    ("speedrun", "absurdle"),
+    ("time_attack", "random"),
+    ("time_attack", "word"),
+    ("time_attack", "daily"),
+    ("time_attack", "date"),
+    ("time_attack", "absurdle"),
+    ("challenge", "random"),
+    ("challenge", "word"),
+    ("challenge", "daily"),
+    ("challenge", "absurdle"),
+];
+
+/**
+the keys that only mean something along with one of the others.
+*/
+pub const REQUIRES: [(&str, &[&str]); 2] = [
+    (
+        "seed",
+        &["random", "daily", "date", "speedrun", "time_attack"],
+    ),
+    ("day", &["random", "speedrun", "time_attack"]),
+];
+
+/**
+a config file read and checked key by key, with the line every key is set on.
+the keys at the top are taken by every profile, the ones of a profile are laid over them,
+like in TOML:
+
+```toml
+state = "wordle.json"
+max_guesses = 6
+
+[profiles.ranked]
+difficulty = "strict"
+
+[profiles.training]
+inherits = "ranked"
+max_guesses = 0
+```
+*/
+#[derive(Debug, Clone)]
+pub struct ConfigFile {
+    pub path: String,
+    pub profile: Option<String>, // the profile the keys at the top were overridden by
+    pub config: Config,
+    lines: Lines, // the line of the key that was taken, at the top or in a profile
+}
+
+impl ConfigFile {
+    /**
+    function: to read a config file, in TOML if its name ends with ".toml" and in JSON otherwise,
+                the keys of a profile laid over the ones at the top and the ones of the profiles it inherits
+    input: path: the path of the file
+            profile: the profile given with "--profile", None for the keys at the top only
+    output: the ConfigFile, a config error naming the key and the line if a key is unknown,
+            a value is not of the type it expects or the profile can't be found
+    */
+    pub fn read(path: &str, profile: Option<&str>) -> Result<ConfigFile, Error> {
+        let wrong = |line: Option<usize>, key: Option<&str>, reason: String| Error::Config {
+            path: String::from(path),
+            line,
+            key: key.map(String::from),
+            reason,
+        };
+        let text = fread(&Some(String::from(path)))
+            .map_err(|err| wrong(None, None, format!("can't be read ({})", err)))?;
+        let toml = Path::new(path)
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+        let mut table = if toml {
+            from_toml(&text)
+        } else {
+            from_json(&text)
+        }
+        .map_err(|(line, reason)| wrong(line, None, reason))?;
+        let profiles = match table.remove("profiles") {
+            None => Map::new(),
+            Some(Value::Object(profiles)) => profiles,
+            Some(value) => {
+                let reason = format!("expected a table of profiles, found {}", describe(&value));
+                let line = line(&text, "profiles", toml, 0);
+                return Err(wrong(line, Some("profiles"), reason));
+            }
+        };
+        let mut lines = lines_of(&text, &table, toml, 0);
+        check(&table, &lines, false)
+            .map_err(|(line, key, reason)| wrong(line, Some(&key), reason))?;
+        let mut sections: HashMap<&str, (&Map<String, Value>, Lines)> = HashMap::new();
+        for (name, body) in &profiles {
+            let start = section(&text, name, toml);
+            let key = format!("profiles.{}", name);
+            let body = match body {
+                Value::Object(body) => body,
+                value => {
+                    let reason = format!("expected a table of keys, found {}", describe(value));
+                    return Err(wrong(start, Some(&key), reason));
+                }
+            };
+            let found = lines_of(&text, body, toml, start.unwrap_or(1) - 1);
+            check(body, &found, true).map_err(|(line, inner, reason)| {
+                wrong(line, Some(&format!("{}.{}", key, inner)), reason)
+            })?;
+            sections.insert(name, (body, found));
+        }
+        let mut names: Vec<&str> = sections.keys().copied().collect();
+        names.sort_by_key(|name| section(&text, name, toml));
+        for name in &names {
+            // every profile is checked, not only the one played with
+            inherit(&sections, name)
+                .map_err(|(line, key, reason)| wrong(line, Some(&key), reason))?;
+        }
+        if let Some(profile) = profile {
+            let chain = match sections.contains_key(profile) {
+                true => inherit(&sections, profile).unwrap(),
+                false => {
+                    names.sort();
+                    let known = match names.len() {
+                        0 => String::from("there is no profile in the file"),
+                        _ => format!("the profiles are {}", names.join(", ")),
+                    };
+                    return Err(wrong(
+                        None,
+                        None,
+                        format!("no profile \"{}\", {}", profile, known),
+                    ));
+                }
+            };
+            for name in chain.iter().rev() {
+                let (body, found) = &sections[name];
+                for (key, value) in body.iter().filter(|(key, _)| *key != "inherits") {
+                    table.insert(key.clone(), value.clone());
+                    match found.get(key) {
+                        Some(line) => lines.insert(key.clone(), *line),
+                        None => lines.remove(key),
+                    };
+                }
+            }
+            if !sections[profile].0.contains_key("state") {
+                // each profile keeps its own state file, the one it inherits is only the pattern
+                if let Some(Value::String(state)) = table.get("state") {
+                    let state = apart(state, profile);
+                    table.insert(String::from("state"), Value::String(state));
+                }
+            }
+        }
+        let config = serde_json::from_value(Value::Object(table))
+            .map_err(|err| wrong(None, None, err.to_string()))?;
+        Ok(ConfigFile {
+            path: String::from(path),
+            profile: profile.map(String::from),
+            config,
+            lines,
+        })
+    }
+
+    /**
+    function: to tell where a key of the file is set
+    input: key: the key
+    output: the path and the line, like "config.toml:3"
+    */
+    pub fn origin(&self, key: &str) -> String {
+        match self.lines.get(key) {
+            Some(line) => format!("{}:{}", self.path, line),
+            None => self.path.clone(),
+        }
+    }
+}
+
+/**
+function: to find the config file loaded when no "--config" is given,
+            "wordle/config.toml" in $XDG_CONFIG_HOME, or in ~/.config if it is not set
+input: None
+output: the path of the file, None if there is no such file
+*/
+pub fn discover() -> Option<String> {
+    let home = match env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    let path = home.join("wordle").join("config.toml");
+    if path.is_file() {
+        Some(path.to_string_lossy().into_owned())
+    } else {
+        None
+    }
+}
+
+/**
+function: to find the pairs of keys that can't be set together and the keys set without the ones they need
+input: config: the config merged from the file and the command line
+        origin: where a key of the merged config was set, like "--random" or "config.toml:3"
+output: what is wrong, a line for each pair or key, empty if nothing is
+*/
+pub fn conflicts(config: &Config, origin: impl Fn(&str) -> String) -> Vec<String> {
+    let values = serde_json::to_value(config).unwrap();
+    let set = |key: &str| !matches!(&values[key], Value::Null | Value::Bool(false));
+    let mut wrong = vec![];
+    for (one, other) in CONFLICTS {
+        if set(one) && set(other) {
+            wrong.push(format!(
+                "{} ({}) can't be used with {} ({})",
+                one,
+                origin(one),
+                other,
+                origin(other)
+            ));
+        }
+    }
+    for (key, needs) in REQUIRES {
+        if set(key) && !needs.iter().any(|need| set(need)) {
+            wrong.push(format!(
+                "{} ({}) needs one of {}",
+                key,
+                origin(key),
+                needs.join(", ")
+            ));
+        }
+    }
+    wrong
+}
+
+/**
+function: to read the keys of a TOML config file, the dates written bare taken as strings
+input: text: the content of the file
+output: the keys and their values, the line and the reason if it is not TOML
+*/
+fn from_toml(text: &str) -> Result<Map<String, Value>, (Option<usize>, String)> {
+    let table = text.parse::<toml::Table>().map_err(|err| {
+        let line = err.span().map(|span| lineof(text, span.start));
+        (line, err.message().trim().replace('\n', ", "))
+    })?;
+    Ok(table
+        .into_iter()
+        .map(|(key, value)| (key, plain(value)))
+        .collect())
+}
+
+/**
+function: to turn a TOML value into JSON, the dates written bare taken as strings at any depth,
+            in the tables of the profiles too
+input: value: the TOML value
+output: the JSON value
+*/
+fn plain(value: toml::Value) -> Value {
+    match value {
+        toml::Value::Datetime(date) => Value::String(date.to_string()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, plain(value)))
+                .collect(),
+        ),
+        toml::Value::Array(array) => Value::Array(array.into_iter().map(plain).collect()),
+        value => serde_json::to_value(value).unwrap(),
+    }
+}
+
+/**
+function: to read the keys of a JSON config file
+input: text: the content of the file
+output: the keys and their values, the line and the reason if it is not a JSON object
+*/
+fn from_json(text: &str) -> Result<Map<String, Value>, (Option<usize>, String)> {
+    match serde_json::from_str::<Value>(text) {
+        Ok(Value::Object(table)) => Ok(table),
+        Ok(value) => Err((
+            None,
+            format!("expected an object of keys, found {}", describe(&value)),
+        )),
+        Err(err) => {
+            let reason = err.to_string();
+            let reason = match reason.rfind(" at line ") {
+                Some(end) => reason[..end].to_string(), // the line is told on its own
+                None => reason,
+            };
+            Err((Some(err.line()), reason))
+        }
+    }
+}
+
+/**
+function: to find the line a key is set on
+input: text: the content of the file
+        key: the key
+        toml: whether the file is in TOML or JSON
+        from: the lines to skip, the ones before the table the key is in
+output: the line counted from 1, None if it is not found
+*/
+fn line(text: &str, key: &str, toml: bool, from: usize) -> Option<usize> {
+    let quoted = format!("\"{}\"", key);
+    if toml {
+        // a bare or a quoted key at the start of a line, followed by "="
+        text.lines()
+            .enumerate()
+            .skip(from)
+            .find(|(_, line)| {
+                let line = line.trim_start();
+                line.strip_prefix(&quoted)
+                    .or_else(|| line.strip_prefix(key))
+                    .is_some_and(|rest| rest.trim_start().starts_with('='))
+            })
+            .map(|(index, _)| index + 1)
+    } else {
+        // a quoted key followed by ":", anywhere as a JSON object may be on one line
+        let offset: usize = text.split_inclusive('\n').take(from).map(str::len).sum();
+        let rest = &text[offset..];
+        rest.match_indices(&quoted)
+            .find(|(start, _)| rest[start + quoted.len()..].trim_start().starts_with(':'))
+            .map(|(start, _)| lineof(text, offset + start))
+    }
+}
+
+/**
+function: to follow what a profile inherits
+input: sections: the keys of every profile and their lines
+        profile: the name of the profile, one of the sections
+output: the profile and the ones it inherits, the one inherited first at the end,
+        the line, the key and the reason if one inherited doesn't exist or they inherit in a loop
+*/
+fn inherit<'a>(
+    sections: &HashMap<&'a str, (&Map<String, Value>, Lines)>,
+    profile: &'a str,
+) -> Result<Vec<&'a str>, Wrong> {
+    let mut chain = vec![profile];
+    loop {
+        let child = chain[chain.len() - 1];
+        let (body, lines) = &sections[child];
+        let name = match body.get("inherits").and_then(Value::as_str) {
+            Some(name) => name,
+            None => return Ok(chain),
+        };
+        let wrong = |reason: String| {
+            let key = format!("profiles.{}.inherits", child);
+            (lines.get("inherits").copied(), key, reason)
+        };
+        let name = match sections.get_key_value(name) {
+            Some((name, _)) => *name,
+            None => return Err(wrong(format!("no profile \"{}\" to inherit", name))),
+        };
+        let looped = chain.contains(&name);
+        chain.push(name);
+        if looped {
+            return Err(wrong(format!(
+                "the profiles inherit in a loop, {}",
+                chain.join(" -> ")
+            )));
+        }
+    }
+}
+
+/**
+function: to find the lines the keys of a table are set on
+input: text: the content of the file
+        table: the keys
+        toml: whether the file is in TOML or JSON
+        from: the lines to skip, the ones before the table
+output: the line of every key found, counted from 1
+*/
+fn lines_of(text: &str, table: &Map<String, Value>, toml: bool, from: usize) -> Lines {
+    table
+        .keys()
+        .filter_map(|key| Some((key.clone(), line(text, key, toml, from)?)))
+        .collect()
+}
+
+/**
+function: to find the line a profile starts on, "[profiles.NAME]" in TOML and "NAME": in JSON
+input: text: the content of the file
+        name: the name of the profile
+        toml: whether the file is in TOML or JSON
+output: the line counted from 1, None if it is not found
+*/
+fn section(text: &str, name: &str, toml: bool) -> Option<usize> {
+    if toml {
+        let headers = [
+            format!("[profiles.{}]", name),
+            format!("[profiles.\"{}\"]", name),
+        ];
+        text.lines()
+            .position(|line| headers.iter().any(|header| line.trim() == header))
+            .map(|index| index + 1)
+    } else {
+        let profiles = line(text, "profiles", false, 0)?;
+        line(text, name, false, profiles - 1)
+    }
+}
+
+/**
+function: to check the keys of a table, at the top of the file or in a profile
+input: table: the keys
+        lines: the line of every key
+        profile: whether the table is a profile, which takes "inherits" too
+output: None, the line, the key and the reason of the first wrong key in the file
+*/
+fn check(table: &Map<String, Value>, lines: &Lines, profile: bool) -> Result<(), Wrong> {
+    let mut keys: Vec<&String> = table.keys().collect();
+    keys.sort_by_key(|key| lines.get(*key)); // the first wrong key in the file is told
+    for key in keys {
+        let line = lines.get(key).copied();
+        let value = &table[key];
+        if profile && key == "inherits" {
+            if !value.is_string() {
+                let reason = format!(
+                    "expected a string, the name of a profile, found {}",
+                    describe(value)
+                );
+                return Err((line, key.clone(), reason));
+            }
+            continue;
+        }
+        let expected = match KEYS.iter().find(|(name, _)| name == key) {
+            Some((_, expected)) => expected,
+            None => {
+                let names: Vec<String> = suggest(key)
+                    .iter()
+                    .map(|name| format!("\"{}\"", name))
+                    .collect();
+                let reason = match names.len() {
+                    0 => String::from("unknown key"),
+                    _ => format!("unknown key, did you mean {}?", names.join(" or ")),
+                };
+                return Err((line, key.clone(), reason));
+            }
+        };
+        let fits = serde_json::from_value::<Config>(json!({ key: value })).is_ok();
+        let zero = value.as_u64() == Some(0) && expected.ends_with("above 0");
+        let unknown = key == "lang"
+            && value
+                .as_str()
+                .is_some_and(|lang| lang.parse::<Lang>().is_err());
+        if !fits || zero || unknown {
+            let reason = format!("expected {}, found {}", expected, describe(value));
+            return Err((line, key.clone(), reason));
+        }
+    }
+    Ok(())
+}
+
+/**
+function: to name the state file of a profile after the one it inherits
+input: state: the state file inherited, like "wordle.json"
+        profile: the name of the profile
+output: the state file of the profile, like "wordle.ranked.json"
+*/
+fn apart(state: &str, profile: &str) -> String {
+    let path = Path::new(state);
+    let extension = match path.extension() {
+        Some(extension) => format!("{}.{}", profile, extension.to_string_lossy()),
+        None => String::from(profile),
+    };
+    path.with_extension(extension)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/**
+function: to count the line a position of the text is on
+input: text: the text
+        position: the byte offset
+output: the line counted from 1
+*/
+fn lineof(text: &str, position: usize) -> usize {
+    text[..position.min(text.len())].matches('\n').count() + 1
+}
+
+/**
+function: to describe a value in an error message
+input: value: the value
+output: its type and the value itself, like "the string \"five\""
+*/
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => String::from("null"),
+        Value::Bool(value) => format!("the boolean {}", value),
+        Value::Number(value) => format!("the number {}", value),
+        Value::String(value) => format!("the string \"{}\"", value),
+        Value::Array(_) => String::from("an array"),
+        Value::Object(_) => String::from("a table"),
+    }
+}
+
+/**
+function: to find the known keys an unknown one is likely a typo of
+input: key: the unknown key
+output: the closest known keys, all of them on a tie, empty if none is close
+*/
+fn suggest(key: &str) -> Vec<&'static str> {
+    let key = key.to_lowercase().replace('-', "_");
+    let distances: Vec<(usize, &str)> = KEYS
+        .iter()
+        .map(|(name, _)| (distance(&key, name), *name))
+        .collect();
+    match distances.iter().map(|(distance, _)| *distance).min() {
+        Some(closest) if closest <= 2 => distances
+            .into_iter()
+            .filter(|(distance, _)| *distance == closest)
+            .map(|(_, name)| name)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/**
+function: to count the letters to insert, delete or replace to turn a word into another
+input: one, other: the words
+output: the Levenshtein distance of the words
+*/
+fn distance(one: &str, other: &str) -> usize {
+    let other: Vec<char> = other.chars().collect();
+    let mut row: Vec<usize> = (0..=other.len()).collect();
+    for (i, a) in one.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in other.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a == *b {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[other.len()]
+}
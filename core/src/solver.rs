@@ -0,0 +1,77 @@
+use crate::{common::color, dictionary::Dictionary, feedback::Feedback};
+
+/*
+    the functions with the concept entropy are inspired by the video with the link:
+    "https://www.bilibili.com/video/BV1zZ4y1k7Jw", author: 3Blue1Brown
+*/
+
+/**
+function: to check what words in the possible answers fits all the guesses-colors results
+input: guesses: the guesses for this round
+        colors: the colors of the guess
+        acceptable: a vec of unchecked possible answers
+output: the actual possible answers
+*/
+pub fn check(guesses: &[String], colors: &[Feedback], acceptable: &[String]) -> Vec<String> {
+    let mut possible_answers = vec![];
+    for ans in acceptable {
+        let mut judge = true;
+        for i in 0..guesses.len() {
+            if colors[i] != color(ans, &guesses[i]) {
+                // simulate every possible answer
+                judge = false;
+                break;
+            }
+        }
+        if judge {
+            possible_answers.push(ans.clone());
+        }
+    }
+    possible_answers
+}
+
+/**
+function: to calculate the information a guess is expected to give
+input: guess: the word to be guessed
+        possible_answers: the possible answers
+output: the expected entropy of the guess
+*/
+pub fn expected_entropy(guess: &str, possible_answers: &[String]) -> f64 {
+    let mut counts = vec![0; Feedback::patterns(guess.chars().count()) as usize];
+    for answer in possible_answers.iter() {
+        // simulate every possible answer and count the each color mode
+        counts[color(answer, guess).code() as usize] += 1;
+    }
+    let mut entropy = 0.0;
+    for count in counts.iter().filter(|count| **count > 0) {
+        // calculate entropy
+        let possibility = (*count as f64) / (possible_answers.len() as f64);
+        entropy += possibility * (-f64::log2(possibility));
+    }
+    entropy
+}
+
+/**
+function: to find the word expected to give the most information
+input: possible_answers: possible answers
+        dict: the word lists of the game
+output: the actual possible answers and the bits of information it is expected to give
+*/
+pub fn max_entropy(possible_answers: &[String], dict: &Dictionary) -> (String, f64) {
+    let acceptset = dict.accepts();
+    if possible_answers.len() == 1 {
+        return (possible_answers[0].clone(), 0.0);
+    }
+    let mut values = vec![];
+    for guess in acceptset.iter() {
+        values.push(expected_entropy(guess, possible_answers));
+    }
+    let mut max = 0;
+    for i in 0..acceptset.len() {
+        // find the max
+        if values[i] > values[max] {
+            max = i;
+        }
+    }
+    (acceptset[max].clone(), values[max])
+}
@@ -0,0 +1,223 @@
+use {
+    crate::{
+        tty_false::{stats_notty, streaks_notty},
+        tty_true::stats_tty,
+        tty_true::string_color_print,
+    },
+    clap::ArgMatches,
+    wordle_core::{
+        alphabet::{lower, upper},
+        common::{config, score, sources, stateload, wordsets, Config, Outcome},
+        error::Error,
+        feedback::Feedback,
+        locale::{Lang, Msg},
+        solver::{check, max_entropy},
+    },
+};
+
+static SHOWN: usize = 20; // the most possible answers listed in an interactive terminal
+
+/*
+function: to narrow the answers down from the clues and suggest the next guess, for "solve",
+            a non interactive terminal gets "CANDIDATES count words" and "SUGGEST guess bits"
+input: is_tty: whether or not this is a interactive terminal
+        matches: the arguments of the subcommand
+output: None, an args error if a clue is wrong or no answer fits them all
+*/
+pub fn solve(is_tty: bool, matches: &ArgMatches) -> Result<(), Error> {
+    let arg = config(matches)?;
+    let dict = wordsets(&arg)?;
+    let length = dict.finals()[0].chars().count();
+    let mut guesses: Vec<String> = vec![];
+    let mut colors: Vec<Feedback> = vec![];
+    for clue in matches.get_many::<String>("clues").into_iter().flatten() {
+        let wrong = |why: &str| Error::Args(format!("the clue {} {}", clue, why));
+        let (guess, pattern) = clue
+            .split_once(':')
+            .ok_or_else(|| wrong("is not GUESS:PATTERN, like crane:RYRRG"))?;
+        let color: Feedback = upper(pattern)
+            .parse()
+            .map_err(|_| wrong("has a pattern of other letters than G, Y and R"))?;
+        if guess.chars().count() != length || color.len() != length {
+            return Err(wrong(&format!("is not of {} letters", length)));
+        }
+        guesses.push(lower(guess));
+        colors.push(color);
+    }
+    let candidates = check(&guesses, &colors, dict.finals());
+    if candidates.is_empty() {
+        return Err(Error::Args(String::from(
+            "no answer of the final set fits all the clues",
+        )));
+    }
+    let (guess, bits) = max_entropy(&candidates, &dict);
+    let words: Vec<String> = candidates.iter().map(|word| upper(word)).collect();
+    if is_tty {
+        let lang = Lang::choose(&arg.lang);
+        let mut shown = words[..words.len().min(SHOWN)].join(" ");
+        if words.len() > SHOWN {
+            shown += " ...";
+        }
+        println!("{}", lang.text(Msg::Candidates(words.len(), &shown)));
+        println!("{}", lang.text(Msg::Suggested(&upper(&guess), bits)));
+    } else {
+        println!("CANDIDATES {} {}", words.len(), words.join(" "));
+        println!("SUGGEST {} {:.4}", upper(&guess), bits);
+    }
+    Ok(())
+}
+
+/*
+function: to read the rounds kept in the state file of the arguments
+input: arg: the ultimate Config struct, with the state file
+output: the answers, the guesses and the results of the rounds, an args error if there is no state file,
+        a state error if it doesn't exist
+*/
+#[allow(clippy::type_complexity)]
+fn kept(arg: &Config) -> Result<(Vec<Vec<String>>, Vec<String>, Vec<Outcome>), Error> {
+    if arg.state.is_none() {
+        return Err(Error::Args(String::from(
+            "no state file to read, give it with --state or in the config file",
+        )));
+    }
+    let (mut answers, mut records, mut guesses, mut results) = (vec![], vec![], vec![], vec![]);
+    if !stateload(
+        &arg.state,
+        &mut answers,
+        &mut records,
+        &mut guesses,
+        &mut results,
+        &mut None,
+    )? {
+        return Err(Error::state(&arg.state, String::from("does not exist")));
+    }
+    Ok((answers, guesses, results))
+}
+
+/*
+function: to show the statistics kept in a state file, for "stats"
+input: is_tty: whether or not this is a interactive terminal
+        matches: the arguments of the subcommand
+output: None, a state error if the file can't be read
+*/
+pub fn statistics(is_tty: bool, matches: &ArgMatches) -> Result<(), Error> {
+    let arg = config(matches)?;
+    let (_, guesses, results) = kept(&arg)?;
+    if is_tty {
+        stats_tty(&guesses, &results, Lang::choose(&arg.lang));
+    } else {
+        stats_notty(&guesses, &results);
+        streaks_notty(&guesses, &results); // the game keeps to the two lines above
+    }
+    Ok(())
+}
+
+/*
+function: to check the word lists and show what they hold, for "dict",
+            a non interactive terminal gets "FINAL", "ACCEPTABLE", "ALPHABET" and "IDENTITY" lines
+input: is_tty: whether or not this is a interactive terminal
+        matches: the arguments of the subcommand
+output: None, a word list error if they are wrong
+*/
+pub fn dict(is_tty: bool, matches: &ArgMatches) -> Result<(), Error> {
+    let arg = config(matches)?;
+    let dict = wordsets(&arg)?;
+    let alphabet: String = dict.alphabet().letters().iter().collect();
+    if is_tty {
+        let lang = Lang::choose(&arg.lang);
+        println!("{}", lang.text(Msg::FinalSet(dict.finals().len())));
+        println!("{}", lang.text(Msg::AcceptableSet(dict.accepts().len())));
+        println!("{}", lang.text(Msg::Alphabet(&alphabet)));
+        println!("{}", lang.text(Msg::Identity(dict.identity())));
+    } else {
+        println!("FINAL {}", dict.finals().len());
+        println!("ACCEPTABLE {}", dict.accepts().len());
+        println!("ALPHABET {}", alphabet);
+        println!("IDENTITY {:08x}", dict.identity());
+    }
+    Ok(())
+}
+
+/*
+function: to show the guesses of a round kept in a state file and their colors, for "replay",
+            a non interactive terminal gets the guess and its pattern on each board a line,
+            then "CORRECT" or "FAILED" as in the game
+input: is_tty: whether or not this is a interactive terminal
+        matches: the arguments of the subcommand
+output: None, an args error if there is no such round, a state error if it has no answer
+*/
+pub fn replay(is_tty: bool, matches: &ArgMatches) -> Result<(), Error> {
+    let arg = config(matches)?;
+    let (answers, guesses, results) = kept(&arg)?;
+    let rounds = answers.len().min(results.len());
+    let round = matches.get_one::<usize>("round").copied().unwrap_or(rounds);
+    if round == 0 || round > rounds {
+        return Err(Error::Args(format!(
+            "there is no round {} in {}, which keeps {}",
+            round,
+            arg.state.unwrap_or_default(),
+            rounds
+        )));
+    }
+    if answers[round - 1].is_empty() {
+        return Err(Error::state(
+            &arg.state,
+            format!("the round {} was kept without its answer", round),
+        ));
+    }
+    let used = |result: &Outcome| result.and_then(|(_, used, _, _)| used).unwrap_or(0);
+    let former: usize = results[..round - 1].iter().map(used).sum(); // the guesses of the rounds before
+    let played = &guesses[former..former + used(&results[round - 1])];
+    let mut ans: Vec<String> = answers[round - 1].iter().map(|ans| upper(ans)).collect();
+    let length = |word: &String| word.chars().count();
+    if let Some(guess) = played
+        .iter()
+        .find(|guess| ans.iter().any(|ans| length(ans) != length(guess)))
+    {
+        return Err(Error::state(
+            &arg.state,
+            format!(
+                "the guess \"{}\" of the round {} is not as long as its answer",
+                guess, round
+            ),
+        ));
+    }
+    let mut solved = vec![None; ans.len()];
+    for (total, guess) in played.iter().enumerate() {
+        let color = score(&mut ans, false, guess, total + 1, &mut solved);
+        if is_tty {
+            string_color_print(guess, &color);
+        } else {
+            let patterns: Vec<String> = color
+                .iter()
+                .map(|board| match board {
+                    Some(board) => board.to_string(),
+                    None => "-".repeat(guess.chars().count()), // solved before it
+                })
+                .collect();
+            println!("{} {}", guess, patterns.join(" "));
+        }
+    }
+    let won = matches!(results[round - 1], Some((Some(true), _, _, _)));
+    let lang = Lang::choose(&arg.lang);
+    match (won, is_tty) {
+        (true, true) => println!("{}", lang.text(Msg::Solved(played.len()))),
+        (true, false) => println!("CORRECT {}", played.len()),
+        (false, true) => println!("{}", lang.text(Msg::AnswerWas(&ans.join(" ")))),
+        (false, false) => println!("FAILED {}", ans.join(" ")),
+    }
+    Ok(())
+}
+
+/*
+function: to print the config a game would be played with, for "config", a line for each value set
+            as "key = value  # source", in the syntax of a TOML config file
+input: matches: the arguments of the subcommand, the same as a game
+output: None, the error the config stops the game with
+*/
+pub fn settings(matches: &ArgMatches) -> Result<(), Error> {
+    for (key, value, source) in sources(matches)? {
+        println!("{} = {}  # {}", key, value, source);
+    }
+    Ok(())
+}
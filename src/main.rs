@@ -1,122 +1,506 @@
-pub mod builtin_words;
-pub mod common;
+pub mod commands;
 pub mod tty_false;
 pub mod tty_true;
-use crate::{
-    common::{args, config, random, stateload, statesave, word, Config, FINALSET},
-    tty_false::{go_on_notty, notty, stats_notty},
-    tty_true::{go_on_tty, stats_tty, tty},
+use {
+    crate::{
+        commands::{dict, replay, settings, solve, statistics},
+        tty_false::{go_on_notty, notty, resume_notty, stats_notty},
+        tty_true::{go_on_tty, resume_tty, stats_tty, tty},
+    },
+    clap::ArgMatches,
+    std::time::Instant,
+    wordle_core::{
+        alphabet::upper,
+        common::{
+            args, budget, clock, config, create, daily, deadline, details, grid, keys, level, pick,
+            place, random, resumable, resumed, seconds, share, sharing, stateload, statesave,
+            time_up, unplayed, word, wordsets, Config, Details, Outcome, Unfinished, DEFAULT_SEED,
+        },
+        dictionary::Dictionary,
+        error::Error,
+        locale::{Lang, Msg},
+    },
 };
+
+// to save the round going on: given the guesses, the results, the valid guesses of the round and its record
+pub type Keep<'a> = &'a dyn Fn(&[String], &[Outcome], usize, &Details) -> Result<(), Error>;
+
 /*
 function: to play a round of game
 input: is_tty: whether or not this is a interactive terminal
         arg: arguments from command line and config file (already processed)
+        dict: the word lists of the game
+        keys: the rows of the keyboard
+        deadline: when the time of a time attack is up, None for the other modes
+        state: the state file saved to as soon as the round is over, None for the modes not kept
+        answers: where stores the answers of every board of all the rounds
+        ans: the answer of each board, the candidates narrowed down to the committed word in the absurdle mode
+        replay: the guesses made before the round was left in the middle, empty for a new round
+        record: how the round is played, kept in records when it ends
+        records: where stores how every round was played
         guesses: where stores every valid guesses the player inputs
         results: where stores the results of every round of games, including win/fail, the times player tried
 output: true to play another game, false to quit
 */
+#[allow(clippy::too_many_arguments)]
 fn round(
     is_tty: bool,
     arg: &Config,
-    ans: &str,
+    dict: &Dictionary,
+    keys: &[Vec<char>],
+    deadline: Option<Instant>,
+    state: &Option<String>,
+    answers: &mut Vec<Vec<String>>,
+    ans: &mut Vec<String>,
+    replay: &[String],
+    mut record: Details,
+    records: &mut Vec<Details>,
     guesses: &mut Vec<String>,
-    results: &mut Vec<Option<(Option<bool>, Option<usize>)>>,
-) -> Result<bool, String> {
+    results: &mut Vec<Outcome>,
+) -> Result<bool, Error> {
     let mut next = false;
-    if is_tty {
+    let limit = budget(arg.max_guesses.unwrap(), arg.boards.unwrap());
+    let timed = arg.speedrun.is_some() || arg.time_attack.is_some();
+    let once = arg.word.is_some()
+        || arg.date.is_some()
+        || (arg.daily.is_some() && arg.daily.unwrap())
+        || timed
+        || !replay.is_empty(); // an answer asigned, the daily puzzle, the timed modes going on by themselves, or a round continued
+    let absurd = arg.absurdle.is_some() && arg.absurdle.unwrap();
+    let lang = Lang::choose(&arg.lang);
+    let kept = if absurd { vec![] } else { ans.clone() }; // the absurdle mode is replayed from all the words
+    let keep = |guesses: &[String], results: &[Outcome], total: usize, record: &Details| {
+        let unfinished = Unfinished {
+            answers: kept.clone(),
+            guesses: guesses[guesses.len() - total..].to_vec(),
+            max_guesses: arg.max_guesses.unwrap(),
+            details: record.clone(),
+        };
+        statesave(
+            state,
+            &answers[..],
+            &records[..],
+            guesses,
+            results,
+            Some(&unfinished),
+        )
+    };
+    let colors = if is_tty {
         //interactive
         tty(
             ans,
-            arg.difficult.is_some() && arg.difficult.unwrap(),
+            level(arg),
+            absurd,
+            limit,
+            dict,
+            keys,
+            deadline,
+            replay,
+            &mut record,
+            &keep,
             guesses,
             results,
-        )?;
-        if arg.stats.is_some() && arg.stats.unwrap() {
-            stats_tty(&guesses, &results);
-        }
-        if !arg.word.is_some() {
-            // no answer asigned in arg
-            next = go_on_tty()?;
-        }
+            lang,
+        )?
     } else {
         notty(
             ans,
-            arg.difficult.is_some() && arg.difficult.unwrap(),
+            level(arg),
+            absurd,
+            limit,
+            dict,
+            deadline,
+            replay,
+            &mut record,
+            &keep,
             guesses,
             results,
-        )?;
-        if arg.stats.is_some() && arg.stats.unwrap() {
-            stats_notty(&guesses, &results);
-        }
-        if !arg.word.is_some() {
-            next = go_on_notty()?;
+        )?
+    };
+    answers.push(ans.clone()); // the word committed to in the absurdle mode
+    records.push(record);
+    statesave(state, answers, records, guesses, results, None)?; // a crash later loses nothing
+    if sharing(arg) {
+        // the day of the daily puzzle, or the number of the round
+        let number = match daily(arg, dict)? {
+            Some((_, day)) => day,
+            None => results.len(),
+        };
+        let ascii = arg.share_ascii.is_some() && arg.share_ascii.unwrap();
+        let block = grid(
+            number,
+            &colors,
+            &results[results.len() - 1],
+            level(arg),
+            ascii,
+        );
+        share(&arg.share_file, &block)?;
+    }
+    if arg.stats.is_some() && arg.stats.unwrap() {
+        if is_tty {
+            stats_tty(guesses, results, lang);
+        } else {
+            stats_notty(guesses, results);
         }
     }
+    if !once {
+        next = if is_tty {
+            go_on_tty(lang)?
+        } else {
+            go_on_notty()?
+        };
+    }
     Ok(next)
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/*
+function: to load the state file if one is given, a new one is started if it doesn't exist yet,
+            and to offer to continue the round left in the middle in it
+input: is_tty: whether or not this is a interactive terminal
+        arg: arguments from command line and config file (already processed)
+        dict: the word lists of the game
+        keys: the rows of the keyboard
+        answers: where stores the answers of every board of all the rounds
+        records: where stores how every round was played
+        guesses: where stores every valid guesses the player inputs
+        results: where stores the results of every round of games
+output: true if a round was continued, which ends the game
+*/
+#[allow(clippy::too_many_arguments)]
+fn load(
+    is_tty: bool,
+    arg: &Config,
+    dict: &Dictionary,
+    keys: &[Vec<char>],
+    answers: &mut Vec<Vec<String>>,
+    records: &mut Vec<Details>,
+    guesses: &mut Vec<String>,
+    results: &mut Vec<Outcome>,
+) -> Result<bool, Error> {
+    let state = match &arg.state {
+        Some(state) => state,
+        None => return Ok(false),
+    };
+    let mut unfinished = None;
+    if !stateload(
+        &arg.state,
+        answers,
+        records,
+        guesses,
+        results,
+        &mut unfinished,
+    )? {
+        // not in the game's output
+        eprintln!("{} does not exist yet, a new state file is started", state);
+    }
+    let unfinished = match unfinished {
+        Some(unfinished) if resumable(&unfinished, dict) => unfinished,
+        Some(_) => {
+            eprintln!("The unfinished round was played with other word lists and is dropped");
+            return Ok(false);
+        }
+        None => return Ok(false),
+    };
+    let resume = if is_tty {
+        resume_tty(&unfinished.guesses, Lang::choose(&arg.lang))?
+    } else {
+        resume_notty(&unfinished.guesses)?
+    };
+    if !resume {
+        return Ok(false); // dropped by the next save
+    }
+    let arg = resumed(arg, &unfinished);
+    let mut ans = if arg.absurdle.unwrap() {
+        dict.finals().iter().map(|ans| upper(ans)).collect()
+    } else {
+        unfinished.answers.clone()
+    };
+    round(
+        is_tty,
+        &arg,
+        dict,
+        keys,
+        None,
+        &arg.state,
+        answers,
+        &mut ans,
+        &unfinished.guesses,
+        unfinished.details,
+        records,
+        guesses,
+        results,
+    )?;
+    Ok(true)
+}
+
+/*
+function: to run the subcommand the arguments ask for, "play" without one
+input: None
+output: None, the error the subcommand stops with
+*/
+fn run() -> Result<(), Error> {
     let is_tty = atty::is(atty::Stream::Stdout);
+    let matches = args();
+    match matches.subcommand() {
+        Some(("play", sub)) => play(is_tty, sub),
+        Some(("solve", sub)) => solve(is_tty, sub),
+        Some(("stats", sub)) => statistics(is_tty, sub),
+        Some(("dict", sub)) => dict(is_tty, sub),
+        Some(("replay", sub)) => replay(is_tty, sub),
+        Some(("config", sub)) => settings(sub),
+        Some(("challenge", challenge)) => {
+            // set a challenge rather than play
+            let sub = challenge.subcommand_matches("create").unwrap();
+            let arg = config(sub)?;
+            println!("{}", create(&arg, &wordsets(&arg)?)?);
+            Ok(())
+        }
+        _ => play(is_tty, &matches),
+    }
+}
+
+/*
+function: to play the game the arguments ask for
+input: is_tty: whether or not this is a interactive terminal
+        matches: the arguments of the game
+output: None, the error the game stops with
+*/
+fn play(is_tty: bool, matches: &ArgMatches) -> Result<(), Error> {
     let mut guesses: Vec<String> = vec![];
-    let mut results: Vec<Option<(Option<bool>, Option<usize>)>> = vec![];
-    let mut answers: Vec<Option<String>> = vec![];
-    let arg = config(&args()?)?;
-    if arg.random.is_some() && arg.random.unwrap() {
+    let mut results: Vec<Outcome> = vec![];
+    let mut answers: Vec<Vec<String>> = vec![];
+    let mut records: Vec<Details> = vec![];
+    let arg = config(matches)?;
+    let dict = wordsets(&arg)?;
+    let keys = keys(&arg, &dict)?;
+    let boards = arg.boards.unwrap();
+    let lang = Lang::choose(&arg.lang);
+    if arg.absurdle.is_some() && arg.absurdle.unwrap() {
+        // when no answer is picked until the game has to
+        if load(
+            is_tty,
+            &arg,
+            &dict,
+            &keys,
+            &mut answers,
+            &mut records,
+            &mut guesses,
+            &mut results,
+        )? {
+            return Ok(()); // the round continued was the game
+        }
+        loop {
+            // every word is a candidate
+            let mut ans: Vec<String> = dict.finals().iter().map(|ans| upper(ans)).collect();
+            let next = round(
+                is_tty,
+                &arg,
+                &dict,
+                &keys,
+                None,
+                &arg.state,
+                &mut answers,
+                &mut ans,
+                &[],
+                details(&arg, &dict),
+                &mut records,
+                &mut guesses,
+                &mut results,
+            )?;
+            if !next {
+                break;
+            }
+        }
+    } else if let Some((today, day)) = daily(&arg, &dict)? {
+        // the daily puzzle, played once
+        if load(
+            is_tty,
+            &arg,
+            &dict,
+            &keys,
+            &mut answers,
+            &mut records,
+            &mut guesses,
+            &mut results,
+        )? {
+            return Ok(()); // the round continued was the game
+        }
+        unplayed(&records, &today)?;
+        let list = 0..dict.finals().len();
+        let mut list = list.collect::<Vec<usize>>();
+        let mut sub = random(&Some(day), &arg.seed.or(Some(DEFAULT_SEED)), &mut list);
+        let mut ans = pick(&list, &mut sub, &dict, &[], boards)?; // the same for everyone
+        let record = Details {
+            day: Some(day),
+            date: Some(today),
+            ..details(&arg, &dict)
+        };
+        round(
+            is_tty,
+            &arg,
+            &dict,
+            &keys,
+            None,
+            &arg.state,
+            &mut answers,
+            &mut ans,
+            &[],
+            record,
+            &mut records,
+            &mut guesses,
+            &mut results,
+        )?;
+    } else if arg.speedrun.is_some() || arg.time_attack.is_some() {
+        // a fixed sequence of seeded answers played against the clock
+        if load(
+            is_tty,
+            &arg,
+            &dict,
+            &keys,
+            &mut answers,
+            &mut records,
+            &mut guesses,
+            &mut results,
+        )? {
+            return Ok(()); // the round continued was the game
+        }
+        let list = 0..dict.finals().len();
+        let mut list = list.collect::<Vec<usize>>();
+        let mut sub = random(
+            &arg.day.or(Some(1)),
+            &arg.seed.or(Some(DEFAULT_SEED)),
+            &mut list,
+        );
+        let first = results.len();
+        let start = Instant::now();
+        let deadline = deadline(&arg);
+        while !matches!(arg.speedrun, Some(rounds) if results.len() - first >= rounds)
+            && !time_up(deadline)
+        {
+            let mut ans = pick(&list, &mut sub, &dict, &[], boards)?; // the same sequence for everyone
+            let record = Details {
+                day: place(&list, &dict, &ans[0]),
+                ..details(&arg, &dict)
+            };
+            round(
+                is_tty,
+                &arg,
+                &dict,
+                &keys,
+                deadline,
+                &arg.state,
+                &mut answers,
+                &mut ans,
+                &[],
+                record,
+                &mut records,
+                &mut guesses,
+                &mut results,
+            )?;
+        }
+        let solved = results[first..]
+            .iter()
+            .filter(|result| matches!(result, Some((Some(true), _, _, _))))
+            .count();
+        let time = seconds(start);
+        match (arg.speedrun, is_tty) {
+            (Some(rounds), true) => println!(
+                "{}",
+                lang.text(Msg::SpeedrunOver(solved, rounds, &clock(time)))
+            ),
+            (Some(rounds), false) => println!("SPEEDRUN {} {} {:.3}", solved, rounds, time),
+            (None, true) => println!(
+                "{}",
+                lang.text(Msg::TimeAttackOver(solved, arg.time_attack.unwrap()))
+            ),
+            (None, false) => println!("TIME ATTACK {}", solved),
+        }
+    } else if arg.random.is_some() && arg.random.unwrap() {
         // when answers are random
-        let list = 0..unsafe { FINALSET.len() };
+        let list = 0..dict.finals().len();
         let mut list = list.collect::<Vec<usize>>();
         let mut sub = random(&arg.day, &arg.seed, &mut list);
-        if arg.state.is_some() {
-            // load information from json file
-            stateload(&arg.state, &mut answers, &mut guesses, &mut results)?;
+        if load(
+            is_tty,
+            &arg,
+            &dict,
+            &keys,
+            &mut answers,
+            &mut records,
+            &mut guesses,
+            &mut results,
+        )? {
+            return Ok(()); // the round continued was the game
         }
         loop {
-            let mut ans = unsafe { &FINALSET[list[sub]] }.to_string();
-            if is_tty {
-                // when interactive, default inputs are uppercase
-                ans = ans.to_ascii_uppercase();
-            }
-            if answers.contains(&Some(ans.clone())) {
-                // ensure the random answers don't duplicate
-                if answers.len() == list.len() {
-                    panic!("All answers in the finalset played");
-                }
-                sub = (sub + 1) % list.len(); //try next answer
-                continue;
-            }
-            answers.push(Some(ans.clone().to_ascii_uppercase()));
-            let next = round(is_tty, &arg, &ans, &mut guesses, &mut results)?; //another round?
-            if next {
-                sub = (sub + 1) % list.len(); // change sub to get a new random answer
-            } else {
+            // the random answers don't duplicate, sub moves on to get new random answers
+            let mut ans = pick(&list, &mut sub, &dict, &answers, boards)?;
+            let record = Details {
+                day: place(&list, &dict, &ans[0]),
+                ..details(&arg, &dict)
+            };
+            let next = round(
+                is_tty,
+                &arg,
+                &dict,
+                &keys,
+                None,
+                &arg.state,
+                &mut answers,
+                &mut ans,
+                &[],
+                record,
+                &mut records,
+                &mut guesses,
+                &mut results,
+            )?; //another round?
+            if !next {
                 break;
             }
         }
-        if arg.state.is_some() {
-            //save information to json file
-            statesave(&arg.state, &answers, &guesses, &results)?;
-        }
     } else {
         if arg.word.is_some() {
             // when answer is decided in the arguments
-            let mut ans = word(&arg.word)?;
-            if is_tty {
-                ans = ans.to_ascii_uppercase();
-            }
-            answers.push(Some(ans.clone().to_ascii_uppercase()));
-            round(is_tty, &arg, &ans, &mut guesses, &mut results)?;
+            let ans = word(&arg.word, &dict, boards)?;
+            let mut ans: Vec<String> = ans.iter().map(|ans| upper(ans)).collect();
+            round(
+                is_tty,
+                &arg,
+                &dict,
+                &keys,
+                None,
+                &None,
+                &mut answers,
+                &mut ans,
+                &[],
+                details(&arg, &dict),
+                &mut records,
+                &mut guesses,
+                &mut results,
+            )?;
         } else {
             loop {
                 // when answers are from input
                 if is_tty {
-                    println!("Input the answer:");
+                    println!("{}", lang.text(Msg::InputAnswer));
                 }
-                let mut ans = word(&arg.word)?;
-                if is_tty {
-                    ans = ans.to_ascii_uppercase();
-                }
-                answers.push(Some(ans.clone().to_ascii_uppercase()));
-                let next = round(is_tty, &arg, &ans, &mut guesses, &mut results)?;
+                let ans = word(&arg.word, &dict, boards)?;
+                let mut ans: Vec<String> = ans.iter().map(|ans| upper(ans)).collect();
+                let next = round(
+                    is_tty,
+                    &arg,
+                    &dict,
+                    &keys,
+                    None,
+                    &None,
+                    &mut answers,
+                    &mut ans,
+                    &[],
+                    details(&arg, &dict),
+                    &mut records,
+                    &mut guesses,
+                    &mut results,
+                )?;
                 if !next {
                     break;
                 }
@@ -125,3 +509,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     Ok(())
 }
+
+/*
+function: to print the error the game stops with, and exit with its code
+input: None
+output: None
+*/
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        std::process::exit(err.code());
+    }
+}
@@ -1,93 +1,144 @@
 use {
-    crate::common::{color, diffcult, exist, input, stats, ACCEPTSET, LENGTH, LIMIT},
-    std::io,
+    crate::Keep,
+    std::{io, time::Instant},
+    wordle_core::{
+        alphabet::{upper, Alphabet},
+        common::{
+            fits, input, now, score, seconds, stats, time_up, used_up, Details, Difficulty, Outcome,
+        },
+        dictionary::Dictionary,
+        error::Error,
+        feedback::{key_char, mark, Feedback, Tile},
+    },
 };
 /*
-function: to change the color into an i32 for easier comparison
-input: color: the color to be transformed
-output: an i32 from the color char
+function: to color and display a keyboard from the new guess and the answer
+input: guess: new guess
+        color: the color of the guess, None if the board was solved before it
+        keyboard: the color of each letter of the alphabet, in its order
+        alphabet: the alphabet of the word lists
+output: None
 */
-pub fn int(color: &char) -> i32 {
-    match color {
-        'G' => 4,
-        'Y' => 3,
-        'R' => 2,
-        'X' => 1,
-        _ => 0,
+pub fn keyboard_out(
+    guess: &str,
+    color: &Option<Feedback>,
+    keyboard: &mut [Option<Tile>],
+    alphabet: &Alphabet,
+) {
+    if let Some(color) = color {
+        for (letter, tile) in guess.chars().zip(color.tiles()) {
+            if let Some(index) = alphabet.index(letter) {
+                mark(&mut keyboard[index], tile); // order in alphabet
+            }
+        }
+    }
+    for key in keyboard.iter() {
+        print!("{}", key_char(*key));
     }
 }
 
 /*
-function: to color and display a keyboard from the new guess and the answer
-input: guess: new guess
-        color: the color of the guess
-        keyboard: a 26-length array of chars each says the color of the letter
+function: to display the color of a guess on a board
+input: color: the color of the guess, None if the board was solved before it
+        length: the word length of the game
 output: None
 */
-pub fn keyboard_out(guess: &str, color: &Vec<char>, keyboard: &mut [char; 26]) {
-    let guess: Vec<char> = guess.chars().collect();
-    let mut sub = [0; 6];
-    for i in 0..LENGTH {
-        sub[i] = guess[i] as usize - 97; // order in alphabet
-        if int(&color[i]) > int(&keyboard[sub[i]]) {
-            keyboard[sub[i]] = color[i];
-        }
-    }
-    for i in 0..26 {
-        print!("{}", keyboard[i]);
+pub fn pattern_out(color: &Option<Feedback>, length: usize) {
+    match color {
+        Some(color) => print!("{} ", color),
+        None => print!("{} ", "-".repeat(length)), // nothing left to solve on this board
     }
 }
 
 /*
 function: to play a round of game in a non interactive terminal
-input: ans: answer for this round of game
+input: ans: the answer of each board for this round of game, all the candidates in the absurdle mode
+        level: the difficulty
+        absurd: the absurdle mode? the answer is committed to when the round ends
+        limit: the max guesses allowed for all the boards, UNLIMITED for no limit
+        dict: the word lists of the game
+        deadline: when the time of a time attack is up, None for the other modes
+        replay: the guesses made before the round was left in the middle, played first
+        record: how the round is played, the invalid guesses and the timestamps are added
+        keep: to save the round going on after every guess, given the guesses, the results,
+            the valid guesses of this round and the record
         guesses: where stores all the valid guesses
         results: where stores the results of all games, including win/lose, mean trails to win a game
-output: None
+output: the color of each guess on each board, None for the boards solved before it
 */
+#[allow(clippy::too_many_arguments)]
 pub fn notty(
-    ans: &str,
-    hard: bool,
+    ans: &mut Vec<String>,
+    level: Difficulty,
+    absurd: bool,
+    limit: usize,
+    dict: &Dictionary,
+    deadline: Option<Instant>,
+    replay: &[String],
+    record: &mut Details,
+    keep: Keep,
     guesses: &mut Vec<String>,
-    results: &mut Vec<Option<(Option<bool>, Option<usize>)>>,
-) -> Result<(), String> {
+    results: &mut Vec<Outcome>,
+) -> Result<Vec<Vec<Option<Feedback>>>, Error> {
+    let start = Instant::now();
+    record.started.get_or_insert_with(now); // when it was first started if continued
+    let mut replay = replay.iter();
     let mut total: usize = 0;
-    let mut keyboard = ['X'; 26];
-    let mut colors: Vec<Vec<char>> = vec![];
+    let boards = if absurd { 1 } else { ans.len() };
+    let mut keyboards = vec![vec![None; dict.alphabet().len()]; boards];
+    let mut colors: Vec<Vec<Option<Feedback>>> = vec![];
+    let mut solved: Vec<Option<usize>> = vec![None; boards];
     loop {
-        let guess = input()?;
-        if exist(&guess[..], unsafe { &ACCEPTSET }) == false {
-            println!("INVALID");
-        } else if hard
-            && total > 0
-            && !diffcult(
-                &guess.to_ascii_uppercase(),
-                &guesses[&guesses.len() - 1],
-                &colors[total - 1],
+        let guess = match replay.next() {
+            Some(guess) => guess.clone(),
+            None => input()?,
+        };
+        if !dict.is_acceptable(&guess)
+            || fits(
+                level,
+                &upper(&guess),
+                &guesses[guesses.len() - total..],
+                &colors,
             )
+            .is_err()
         {
             println!("INVALID");
+            record
+                .invalid
+                .get_or_insert_with(Vec::new)
+                .push(upper(&guess));
         } else {
             // valid guess
             total += 1;
-            guesses.push(guess.clone().to_ascii_uppercase());
-            let color = color(ans, &guess);
-            colors.push(color.clone());
-            print!("{} ", color.iter().collect::<String>());
-            keyboard_out(&guess, &color, &mut keyboard);
+            guesses.push(upper(&guess));
+            let color = score(ans, absurd, &guess, total, &mut solved);
+            for board in color.iter() {
+                pattern_out(board, guess.chars().count());
+            } // one pattern for each board
+            for (i, (board, keyboard)) in color.iter().zip(keyboards.iter_mut()).enumerate() {
+                if i > 0 {
+                    print!(" ");
+                }
+                keyboard_out(&guess, board, keyboard, dict.alphabet());
+            } // then one keyboard for each board
+            colors.push(color);
             println!();
         }
 
-        if guess == ans {
+        let time = seconds(start);
+        record.finished = Some(now());
+        if solved.iter().all(|board| board.is_some()) {
             println!("CORRECT {}", total); // win
-            results.push(Some((Some(true), Some(total))));
-            break Ok(());
-        } else if total == LIMIT {
-            // lose
-            println!("FAILED {}", ans.to_ascii_uppercase());
-            results.push(Some((Some(false), Some(LIMIT))));
-            break Ok(());
+            results.push(Some((Some(true), Some(total), limit, Some(time))));
+            break Ok(colors);
+        } else if used_up(total, limit) || time_up(deadline) {
+            // lose, or the time attack is over
+            ans.truncate(boards); // the absurdle mode commits to a word left
+            println!("FAILED {}", upper(&ans.join(" ")));
+            results.push(Some((Some(false), Some(total), limit, Some(time))));
+            break Ok(colors);
         }
+        keep(guesses, results, total, record)?;
     }
 }
 
@@ -96,21 +147,41 @@ function: to decide whether or not to play another game
 input: None
 output: true to play another game, false to quit
 */
-pub fn go_on_notty() -> Result<bool, String> {
+pub fn go_on_notty() -> Result<bool, Error> {
     let mut input = String::new();
     match io::stdin().read_line(&mut input) {
         Ok(x) => {
             if x == 0 {
-                return Ok(true);
+                Ok(true)
             } else {
                 match input.trim() {
                     "Y" => Ok(true),
                     "N" => Ok(false),
-                    _ => Err(String::from("Input Error")),
+                    answer => Err(Error::Input(format!("expected Y or N, not \"{}\"", answer))),
                 }
             }
         }
-        Err(_) => Err(String::from("Input Error")),
+        Err(_) => Err(Error::Input(String::from("expected Y or N"))),
+    }
+}
+
+/*
+function: to offer to continue the round left in the middle in the state file,
+            "UNFINISHED" is printed with the guesses made in the round, then "Y" or "N" is read
+input: replay: the guesses made in the round
+output: true to continue it, false to drop it
+*/
+pub fn resume_notty(replay: &[String]) -> Result<bool, Error> {
+    println!("UNFINISHED {}", replay.join(" "));
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) => Ok(false),
+        Ok(_) => match input.trim() {
+            "Y" => Ok(true),
+            "N" => Ok(false),
+            answer => Err(Error::Input(format!("expected Y or N, not \"{}\"", answer))),
+        },
+        Err(_) => Err(Error::Input(String::from("expected Y or N"))),
     }
 }
 
@@ -120,15 +191,39 @@ input: guesses: where stores all the valid guesses
         results: where stores the results of all games, including win/lose, mean trails to win a game
 output: None
 */
-pub fn stats_notty(guesses: &Vec<String>, results: &Vec<Option<(Option<bool>, Option<usize>)>>) {
-    let append = stats(&guesses, &results);
-    println!("{} {} {:.2}", (append.0).0, (append.0).1, (append.0).2);
-    let mut last: usize = 5;
-    if (append.1).len() < 5 {
-        last = (append.1).len();
-    }
-    for i in 0..last - 1 {
-        print!("{} {} ", ((append.1)[i].0), (append.1)[i].1);
-    }
-    println!("{} {}", ((append.1)[last - 1].0), (append.1)[last - 1].1);
+pub fn stats_notty(guesses: &[String], results: &[Outcome]) {
+    let append = stats(guesses, results);
+    println!("{} {} {:.2}", append.wins, append.losses, append.average);
+    let words: Vec<String> = append
+        .words
+        .iter()
+        .take(5)
+        .map(|(word, times)| format!("{} {}", word, times))
+        .collect();
+    println!("{}", words.join(" ")); // empty before the first guess
+}
+
+/*
+function: to display the streaks and the guess distribution in a non iteractive terminal, for "stats",
+            "played win% current-streak max-streak" followed by the counts of the guess
+            distribution from 1 to 6 and of the failed rounds
+input: guesses: where stores all the valid guesses
+        results: where stores the results of all games, including win/lose, mean trails to win a game
+output: None
+*/
+pub fn streaks_notty(guesses: &[String], results: &[Outcome]) {
+    let append = stats(guesses, results);
+    let bars: Vec<String> = append
+        .bars()
+        .iter()
+        .map(|(_, count)| count.to_string())
+        .collect();
+    println!(
+        "{} {:.0} {} {} {}",
+        append.played(),
+        append.win_rate(),
+        append.current_streak,
+        append.max_streak,
+        bars.join(" ")
+    );
 }
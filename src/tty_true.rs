@@ -1,34 +1,55 @@
 use {
-    crate::common::{color, diffcult, exist, input, stats, ACCEPTSET, LENGTH, LIMIT},
-    std::io,
+    crate::Keep,
+    std::{io, time::Instant},
+    wordle_core::{
+        alphabet::upper,
+        common::{
+            clock, fits, input, now, score, seconds, stats, time_up, used_up, Details, Difficulty,
+            Outcome, Stats, DISTRIBUTION,
+        },
+        dictionary::Dictionary,
+        error::Error,
+        feedback::{mark, Feedback, Tile},
+        locale::{Lang, Msg},
+    },
 };
 
+static HISTOGRAM: usize = 20; // the width of the longest bar of the guess distribution
+
 /*
 function: to print a letter according the color
 input: letter: letter to be printed
-        color: the color of the letter
+        color: the color of the letter, None for a letter not guessed yet
 output: None
 */
-pub fn char_color_print(letter: &char, color: &char) {
+pub fn char_color_print(letter: &char, color: Option<Tile>) {
     match color {
-        'G' => print!("{}", console::style(letter).bold().green()),
-        'Y' => print!("{}", console::style(letter).bold().yellow()),
-        'R' => print!("{}", console::style(letter).bold().red()),
-        'X' => print!("{}", console::style(letter).bold().white()),
-        _ => {}
+        Some(Tile::Green) => print!("{}", console::style(letter).bold().green()),
+        Some(Tile::Yellow) => print!("{}", console::style(letter).bold().yellow()),
+        Some(Tile::Red) => print!("{}", console::style(letter).bold().red()),
+        None => print!("{}", console::style(letter).bold().white()),
     }
 }
 
 /*
-function: to print a word according the color
+function: to print a word according the color on every board
 input: guess: word to be printed
-        color: the colors of each letter in the word
+        color: the colors of each letter in the word on each board, None if the board was solved before it
 output: None
 */
-pub fn string_color_print(guess: &str, color: &Vec<char>) {
-    let guess: Vec<char> = guess.clone().chars().collect();
-    for i in 0..LENGTH {
-        char_color_print(&guess[i], &color[i]);
+pub fn string_color_print(guess: &str, color: &[Option<Feedback>]) {
+    for (i, board) in color.iter().enumerate() {
+        if i > 0 {
+            print!("  ");
+        }
+        match board {
+            Some(board) => {
+                for (letter, tile) in guess.chars().zip(board.tiles()) {
+                    char_color_print(&letter, Some(tile));
+                }
+            }
+            None => print!("{}", "-".repeat(guess.chars().count())), // nothing left on this board
+        }
     }
     println!();
 }
@@ -36,154 +57,204 @@ pub fn string_color_print(guess: &str, color: &Vec<char>) {
 /*
 function: to give back a letter's loaction on keyboard
 input: letter: the target letter
-output: the coordinate of the letter
+        keys: the rows of the keyboard
+output: the coordinate of the letter, None if it is not on the keyboard
 */
-pub(crate) fn char2location(letter: char) -> (usize, usize) {
-    let keyboard = vec![
-        vec!['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
-        vec!['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L'],
-        vec!['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
-    ];
-    for h in 0..3 {
-        for v in 0..keyboard[h].len() {
-            if letter == keyboard[h][v] {
-                return (h, v);
-            }
+pub(crate) fn char2location(letter: char, keys: &[Vec<char>]) -> Option<(usize, usize)> {
+    for (h, line) in keys.iter().enumerate() {
+        if let Some(v) = line.iter().position(|key| *key == letter) {
+            return Some((h, v));
         }
     }
-    panic!("char 2 loaction error");
+    None
 }
 
 /*
 function: to color a keyboard from the new guess and the answer
 input: guess: new guess
         color: the color of the guess
-        keyboard: a 26-length array of chars each says the color of the letter
+        keyboard: the rows of keys each says the color of the letter
+        keys: the rows of the keyboard
 output: None
 */
-pub(crate) fn change_keyboard(guess: &String, color: &Vec<char>, keyboard: &mut Vec<Vec<char>>) {
-    let guess = guess.chars().collect::<Vec<char>>();
-    for i in 0..LENGTH {
-        let sub = char2location(guess[i]);
-        if int(color[i]) > int(keyboard[sub.0][sub.1]) {
-            keyboard[sub.0][sub.1] = color[i];
-        }
+pub(crate) fn change_keyboard(
+    guess: &str,
+    color: &Feedback,
+    keyboard: &mut [Vec<Option<Tile>>],
+    keys: &[Vec<char>],
+) {
+    for (letter, tile) in guess.chars().zip(color.tiles()) {
+        if let Some((h, v)) = char2location(letter, keys) {
+            mark(&mut keyboard[h][v], tile);
+        } // every letter of the alphabet is on the keyboard, the guesses are spelled with it
     }
 }
 
 /*
-function: to display a colored keyboard
-input: keyboard: the vec contains each letter's colorchar
+function: to display the colored keyboards of every board side by side
+input: keyboards: the vec contains each letter's color for each board
+        keys: the rows of the keyboard
 output: None
 */
-pub fn keyboardout(keyboard: &Vec<Vec<char>>) {
-    let stdkeyboard = vec![
-        vec!['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
-        vec!['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L'],
-        vec!['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
-    ];
-    for i in 0..3 {
-        for j in 0..keyboard[i].len() {
-            char_color_print(&stdkeyboard[i][j], &keyboard[i][j]);
+pub fn keyboardout(keyboards: &[Vec<Vec<Option<Tile>>>], keys: &[Vec<char>]) {
+    let widest = keys.iter().map(|row| row.len()).max().unwrap_or(0);
+    for i in 0..keys.len() {
+        for (b, keyboard) in keyboards.iter().enumerate() {
+            if b > 0 {
+                print!("{}", " ".repeat(2 + widest - keyboard[i].len()));
+            } // keep the boards aligned
+            for j in 0..keyboard[i].len() {
+                char_color_print(&keys[i][j], keyboard[i][j]);
+            }
         }
         println!();
     }
 }
 
-/*
-function: to change the color into an i32 for easier comparison
-input: color: the color to be transformed
-output: an i32 from the color char
-*/
-pub fn int(color: char) -> i32 {
-    match color {
-        'G' => 4,
-        'Y' => 3,
-        'R' => 2,
-        'X' => 1,
-        _ => 0,
-    }
-}
-
 /*
 function: to play a round of game in an interactive terminal
-input: ans: answer for this round of game
+input: ans: the answer of each board for this round of game, all the candidates in the absurdle mode
+        level: the difficulty
+        absurd: the absurdle mode? the answer is committed to when the round ends
+        limit: the max guesses allowed for all the boards, UNLIMITED for no limit
+        dict: the word lists of the game
+        keys: the rows of the keyboard
+        deadline: when the time of a time attack is up, None for the other modes
+        replay: the guesses made before the round was left in the middle, played first
+        record: how the round is played, the invalid guesses and the timestamps are added
+        keep: to save the round going on after every guess, given the guesses, the results,
+            the valid guesses of this round and the record
         guesses: where stores all the valid guesses
         results: where stores the results of all games, including win/lose, mean trails to win a game
-output: None
+        lang: the language of the messages
+output: the color of each guess on each board, None for the boards solved before it
 */
+#[allow(clippy::too_many_arguments)]
 pub fn tty(
-    ans: &str,
-    hard: bool,
+    ans: &mut Vec<String>,
+    level: Difficulty,
+    absurd: bool,
+    limit: usize,
+    dict: &Dictionary,
+    keys: &[Vec<char>],
+    deadline: Option<Instant>,
+    replay: &[String],
+    record: &mut Details,
+    keep: Keep,
     guesses: &mut Vec<String>,
-    results: &mut Vec<Option<(Option<bool>, Option<usize>)>>,
-) -> Result<(), String> {
+    results: &mut Vec<Outcome>,
+    lang: Lang,
+) -> Result<Vec<Vec<Option<Feedback>>>, Error> {
+    let start = Instant::now();
+    record.started.get_or_insert_with(now); // when it was first started if continued
+    let mut replay = replay.iter();
     let mut total: usize = 0;
-    let mut colors: Vec<Vec<char>> = vec![];
-    let mut invaid: Vec<String> = vec![];
-    let mut keyboard = vec![
-        vec!['X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X'],
-        vec!['X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X'],
-        vec!['X', 'X', 'X', 'X', 'X', 'X', 'X'],
-    ];
+    let boards = if absurd { 1 } else { ans.len() };
+    let mut colors: Vec<Vec<Option<Feedback>>> = vec![];
+    let mut invaid: Vec<(String, String)> = vec![]; // the invalid guesses and why
+    let keyboard: Vec<Vec<Option<Tile>>> = keys.iter().map(|row| vec![None; row.len()]).collect();
+    let mut keyboards = vec![keyboard; boards];
+    let mut solved: Vec<Option<usize>> = vec![None; boards];
     loop {
-        println!("Input your guess: ");
-        let guess = input()?;
-        let guessfmt = guess.to_ascii_uppercase();
-        if exist(&guess, unsafe { &ACCEPTSET }) == false {
-            invaid.push(guess.clone());
-            println!("INVALID");
-        } else if hard
-            && total > 0
-            && !diffcult(&guess, &guesses[guesses.len() - 1], &colors[total - 1])
-        {
-            invaid.push(guess.clone());
-            println!("INVALID");
+        let guess = match replay.next() {
+            Some(guess) => guess.clone(),
+            None => {
+                println!("{}", lang.text(Msg::InputGuess));
+                input()?
+            }
+        };
+        let guessfmt = upper(&guess);
+        let checked = if dict.is_acceptable(&guess) {
+            fits(level, &guessfmt, &guesses[guesses.len() - total..], &colors)
+                .map_err(|rule| lang.text(Msg::Rule(rule)))
+        } else {
+            Err(lang.text(Msg::NotInList))
+        };
+        if let Err(reason) = checked {
+            println!("{}", lang.text(Msg::Invalid(&reason)));
+            invaid.push((guess.clone(), reason));
+            record
+                .invalid
+                .get_or_insert_with(Vec::new)
+                .push(guessfmt.clone());
         } else {
             // valid
             guesses.push(guessfmt.clone());
             total += 1;
-            let thiscolor = color(ans, &guessfmt);
-            colors.push(thiscolor.clone());
-            change_keyboard(&guessfmt, &thiscolor, &mut keyboard);
+            let thiscolor = score(ans, absurd, &guessfmt, total, &mut solved);
+            for (board, keyboard) in thiscolor.iter().zip(keyboards.iter_mut()) {
+                if let Some(board) = board {
+                    change_keyboard(&guessfmt, board, keyboard, keys);
+                }
+            }
+            colors.push(thiscolor);
         }
         for i in guesses.len() - total..guesses.len() {
             // print valid answer
             string_color_print(&guesses[i], &colors[i + total - guesses.len()])
         }
-        for i in 0..invaid.len() {
+        for (word, reason) in invaid.iter() {
             // print invalid answer
-            println!("{}: INVALID", invaid[i]);
+            println!("{}", lang.text(Msg::InvalidGuess(word, reason)));
         }
-        keyboardout(&keyboard);
-        if guessfmt == ans {
-            println!("CORRECT {}", total);
-            results.push(Some((Some(true), Some(total))));
-            break Ok(());
-        } else if total == LIMIT {
-            println!("FAILED, {}", ans);
-            results.push(Some((Some(false), Some(LIMIT))));
-            break Ok(());
+        keyboardout(&keyboards, keys);
+        let time = seconds(start);
+        record.finished = Some(now());
+        if solved.iter().all(|board| board.is_some()) {
+            println!("{}", lang.text(Msg::Correct(total, &clock(time))));
+            results.push(Some((Some(true), Some(total), limit, Some(time))));
+            break Ok(colors);
+        } else if used_up(total, limit) || time_up(deadline) {
+            ans.truncate(boards); // the absurdle mode commits to a word left
+            if time_up(deadline) {
+                println!("{}", lang.text(Msg::TimeUp(&ans.join(" "))));
+            } else {
+                println!("{}", lang.text(Msg::Failed(&ans.join(" "))));
+            }
+            results.push(Some((Some(false), Some(total), limit, Some(time))));
+            break Ok(colors);
         }
+        keep(guesses, results, total, record)?;
     }
 }
 
 /*
 function: to decide whether or not to play another game
-input: None
+input: lang: the language of the messages
 output: true to play another game, false to quit
 */
-pub fn go_on_tty() -> Result<bool, String> {
-    println!("Another round? (Input 'Y' to start another round, 'N' to end the game.)");
+pub fn go_on_tty(lang: Lang) -> Result<bool, Error> {
+    println!("{}", lang.text(Msg::AnotherRound));
     let mut input = String::new();
     match io::stdin().read_line(&mut input) {
         Ok(0) => Ok(false),
         Ok(2) => match input.trim() {
             "Y" => Ok(true),
             "N" => Ok(false),
-            _ => Err(String::from("Input Error")),
+            answer => Err(Error::Input(format!("expected Y or N, not \"{}\"", answer))),
+        },
+        _ => Err(Error::Input(String::from("expected Y or N"))),
+    }
+}
+
+/*
+function: to offer to continue the round left in the middle in the state file
+input: replay: the guesses made in the round
+        lang: the language of the messages
+output: true to continue it, false to drop it
+*/
+pub fn resume_tty(replay: &[String], lang: Lang) -> Result<bool, Error> {
+    println!("{}", lang.text(Msg::LeftRound(replay)));
+    println!("{}", lang.text(Msg::ContinueRound));
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) => Ok(false),
+        Ok(_) => match input.trim() {
+            "Y" => Ok(true),
+            "N" => Ok(false),
+            answer => Err(Error::Input(format!("expected Y or N, not \"{}\"", answer))),
         },
-        _ => Err(String::from("Input Error")),
+        _ => Err(Error::Input(String::from("expected Y or N"))),
     }
 }
 
@@ -191,34 +262,81 @@ pub fn go_on_tty() -> Result<bool, String> {
 function: to display the statistics in an iteractive terminal
 input: guesses: where stores all the valid guesses
         results: where stores the results of all games, including win/lose, mean trails to win a game
+        lang: the language of the messages
 output: None
 */
-pub fn stats_tty(guesses: &Vec<String>, results: &Vec<Option<(Option<bool>, Option<usize>)>>) {
+pub fn stats_tty(guesses: &[String], results: &[Outcome], lang: Lang) {
     let append = stats(guesses, results);
-    print!("Up to now, you have won {} round", (append.0).0);
-    if (append.0).0 > 1 {
-        print!("s");
-    }
-    print!(", lost {} round", (append.0).1);
-    if (append.0).1 > 1 {
-        print!("s");
-    }
-    print!(", and tried {:.2} time", (append.0).2);
-    if (append.0).2 > 1.0 {
-        print!("s");
-    }
-    println!(" on average to win a round.");
+    println!(
+        "{}",
+        lang.text(Msg::Summary(append.wins, append.losses, append.average))
+    );
+    println!(
+        "{}",
+        lang.text(Msg::Streaks(
+            append.played(),
+            append.win_rate(),
+            append.current_streak,
+            append.max_streak
+        ))
+    );
+    histogram(&append, results.last(), lang);
+    if let (Some(best), Some(average)) = (append.best_time, append.average_time) {
+        println!("{}", lang.text(Msg::Times(&clock(best), &clock(average))));
+    } // only the rounds won with their time kept
     let mut last: usize = 5;
-    if (append.1).len() < 5 {
-        last = (append.1).len();
+    if append.words.len() < 5 {
+        last = append.words.len();
     }
-    println!("These are the words you have used relatively more frequently: ");
+    println!("{}", lang.text(Msg::FrequentWords));
     for i in 0..last {
-        print!("{},{}time", (append.1)[i].0, (append.1)[i].1);
-        if (append.1)[i].1 > 1 {
-            print!("s");
-        }
-        print!("; ");
+        print!(
+            "{}; ",
+            lang.text(Msg::WordUsed(&append.words[i].0, append.words[i].1))
+        );
     }
     println!();
 }
+
+/*
+function: to draw the guess distribution as a histogram, the bar of the last round in green
+input: append: the statistics
+        last: the result of the last round
+        lang: the language of the messages
+output: None
+*/
+fn histogram(append: &Stats, last: Option<&Outcome>, lang: Lang) {
+    let bars = append.bars();
+    let highest = bars
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let current = match last {
+        Some(Some((Some(true), Some(total), _, _))) => Some((*total).clamp(1, DISTRIBUTION) - 1),
+        Some(Some((Some(false), _, _, _))) => Some(DISTRIBUTION), // the failed bar
+        _ => None,
+    };
+    println!("{}", lang.text(Msg::Distribution));
+    let widest = bars.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    for (i, (label, count)) in bars.iter().enumerate() {
+        let width = (count * HISTOGRAM).div_ceil(highest); // a round is never drawn empty
+        let bar = "\u{2588}".repeat(width);
+        if current == Some(i) {
+            println!(
+                "{:<widest$} | {} {}",
+                label,
+                console::style(bar).green(),
+                count
+            );
+        } else {
+            println!(
+                "{:<widest$} | {} {}",
+                label,
+                console::style(bar).white(),
+                count
+            );
+        }
+    }
+}
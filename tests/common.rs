@@ -1,3 +1,5 @@
+#![allow(dead_code)] // each test file only uses a part of the helpers
+
 use std::env::consts::EXE_EXTENSION;
 use std::fs::File;
 use std::io::prelude::*;
@@ -9,7 +11,6 @@ use std::sync::Once;
 use assert_json_diff::assert_json_eq;
 use lazy_static::lazy_static;
 use pretty_assertions::assert_eq;
-use serde_json;
 
 // The code was originally written by Jack O'Connor (@oconnor663)
 // Taken from https://github.com/oconnor663/os_pipe.rs/blob/f41c58e503e1efc5e4d0edfcd2e756b3a81b4232/src/lib.rs#L281-L314
@@ -41,7 +42,7 @@ fn build_and_find_path(name: &str) -> PathBuf {
     static CARGO_BUILD_ONCE: Once = Once::new();
     CARGO_BUILD_ONCE.call_once(|| {
         let mut build_command = Command::new("cargo");
-        build_command.args(&["build", "--quiet"]);
+        build_command.args(["build", "--quiet"]);
         if !cfg!(debug_assertions) {
             build_command.arg("--release");
         }
@@ -71,6 +72,7 @@ pub struct TestCase {
     arguments: Vec<String>,
     input: String,
     answer: String,
+    config_home: PathBuf, // $XDG_CONFIG_HOME, where the config file of the user would be discovered
 }
 
 impl TestCase {
@@ -94,12 +96,19 @@ impl TestCase {
                 .collect(),
             input: in_content,
             answer: ans_content,
+            config_home: case_dir, // no wordle/config.toml, the one of the user is not loaded
         }
     }
 
+    pub fn with_config_home(mut self, dir: &str) -> Self {
+        self.config_home = PathBuf::from(dir);
+        self
+    }
+
     fn execute_program_and_feed_input(&self) -> Child {
         let mut command = Command::new(EXE_PATH.as_os_str())
             .args(&self.arguments)
+            .env("XDG_CONFIG_HOME", &self.config_home)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .spawn()
@@ -161,14 +170,71 @@ impl TestCase {
         self.run_and_compare_result();
 
         // load state and compare with answer
-        let run_state: serde_json::Value =
+        let mut run_state: serde_json::Value =
+            serde_json::from_reader(BufReader::new(File::open(&run_state_file).unwrap())).unwrap();
+        let answer_state: serde_json::Value =
+            serde_json::from_reader(BufReader::new(File::open(&after_state_file).unwrap()))
+                .unwrap();
+        placeheld(&mut run_state, &answer_state);
+        assert_json_eq!(run_state, answer_state);
+    }
+
+    pub fn run_and_compare_new_game_state(&mut self) {
+        // no state before, the file is created by the run
+        let case_dir = Path::new("tests").join("cases");
+        let run_state_file = case_dir.join(format!("{}.run.json", self.name));
+        let after_state_file = case_dir.join(format!("{}.after.json", self.name));
+        if run_state_file.exists() {
+            std::fs::remove_file(&run_state_file).unwrap();
+        }
+        self.arguments.append(&mut vec![
+            "--state".to_string(),
+            run_state_file.to_str().unwrap().to_string(),
+        ]);
+        self.run_and_compare_result();
+
+        let mut run_state: serde_json::Value =
             serde_json::from_reader(BufReader::new(File::open(&run_state_file).unwrap())).unwrap();
         let answer_state: serde_json::Value =
             serde_json::from_reader(BufReader::new(File::open(&after_state_file).unwrap()))
                 .unwrap();
+        placeheld(&mut run_state, &answer_state);
         assert_json_eq!(run_state, answer_state);
     }
 
+    pub fn run_and_check_timestamps(&mut self) {
+        // the rounds played are stamped in RFC 3339 with the time they started and finished at
+        self.run_and_compare_new_game_state();
+        let run_state_file = Path::new("tests")
+            .join("cases")
+            .join(format!("{}.run.json", self.name));
+        let run_state: serde_json::Value =
+            serde_json::from_reader(BufReader::new(File::open(&run_state_file).unwrap())).unwrap();
+        for game in run_state["games"].as_array().unwrap() {
+            let started = game["started"].as_str().unwrap();
+            let finished = game["finished"].as_str().unwrap();
+            for stamp in [started, finished] {
+                let shape: String = stamp
+                    .chars()
+                    .map(|ch| if ch.is_ascii_digit() { '0' } else { ch })
+                    .collect();
+                assert!(
+                    shape == "0000-00-00T00:00:00+00:00" || shape == "0000-00-00T00:00:00-00:00",
+                    "case {}: {} is not in RFC 3339",
+                    self.name,
+                    stamp
+                );
+            }
+            assert!(
+                started <= finished,
+                "case {}: the round finished at {} before it started at {}",
+                self.name,
+                finished,
+                started
+            );
+        }
+    }
+
     pub fn run_and_expect_exit(&self) {
         let command = self.execute_program_and_feed_input();
         assert!(
@@ -181,4 +247,76 @@ impl TestCase {
             self.name
         );
     }
+
+    pub fn run_and_expect_code(&self, code: i32) {
+        let command = self.execute_program_and_feed_input();
+        assert_eq!(
+            command
+                .wait_with_output()
+                .expect("failed to wait on process")
+                .status
+                .code(),
+            Some(code),
+            "case {} should exit with code {}",
+            self.name,
+            code
+        );
+    }
+
+    pub fn run_and_compare_error(&self, code: i32) {
+        // the message printed on stderr is compared with the answer
+        let mut command = Command::new(EXE_PATH.as_os_str())
+            .args(&self.arguments)
+            .env("XDG_CONFIG_HOME", &self.config_home)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to execute process");
+        command
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(self.input.as_bytes())
+            .unwrap();
+        let output = command
+            .wait_with_output()
+            .expect("failed to wait on process");
+        assert_eq!(
+            output.status.code(),
+            Some(code),
+            "case {} should exit with code {}",
+            self.name,
+            code
+        );
+        assert_eq!(
+            String::from_utf8(output.stderr).unwrap().trim(),
+            self.answer.trim(),
+            "case {} incorrect",
+            self.name
+        );
+    }
+}
+
+// the values of the state file no test can know in advance, like the time a round took, are
+// written as "*" in the answer: the run has to keep a value there, whatever it is
+fn placeheld(run: &mut serde_json::Value, answer: &serde_json::Value) {
+    match (run, answer) {
+        (serde_json::Value::Object(run), serde_json::Value::Object(answer)) => {
+            for (key, expected) in answer {
+                if let Some(value) = run.get_mut(key) {
+                    placeheld(value, expected);
+                }
+            }
+        }
+        (serde_json::Value::Array(run), serde_json::Value::Array(answer)) => {
+            for (value, expected) in run.iter_mut().zip(answer) {
+                placeheld(value, expected);
+            }
+        }
+        (run, serde_json::Value::String(expected)) if expected == "*" => {
+            *run = serde_json::Value::String(String::from("*"));
+        }
+        _ => {}
+    }
 }
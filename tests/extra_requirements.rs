@@ -0,0 +1,274 @@
+use ntest::timeout;
+
+mod common;
+use common::TestCase;
+
+#[test]
+#[timeout(2000)]
+fn test_09_word_length() {
+    // six-letter words from a custom word list
+    TestCase::read("09_01_word_length").run_and_compare_result();
+    // word length out of range
+    TestCase::read("09_02_invalid_word_length").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_10_max_guesses() {
+    // three guesses a round, saved with the state
+    TestCase::read("10_01_max_guesses").run_and_compare_game_state();
+    // no limit on the guesses
+    TestCase::read("10_02_unlimited_guesses").run_and_compare_result();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_11_multi_boards() {
+    // two random boards, saved with the answer and the solving guess of each board
+    TestCase::read("11_01_multi_boards").run_and_compare_game_state();
+    // four boards with one extra guess each, solved boards shown as dashes
+    TestCase::read("11_02_quordle").run_and_compare_result();
+    // the max guesses of a single board kept for several boards, not the guesses of the round
+    TestCase::read("11_03_boards_limit").run_and_compare_new_game_state();
+}
+
+#[test]
+#[timeout(2000)]
+fn test_12_absurdle() {
+    // the answer keeps dodging until a single candidate is left
+    TestCase::read("12_01_absurdle").run_and_compare_game_state();
+    // out of guesses, the word the game committed to is revealed
+    TestCase::read("12_02_absurdle_failed").run_and_compare_result();
+    // there is no answer to pick in the absurdle mode
+    TestCase::read("12_03_absurdle_conflict").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_13_strict_difficulty() {
+    // greys banned and yellows kept out of the slots they were found in
+    TestCase::read("13_01_strict").run_and_compare_result();
+    // the difficulty set in the config file
+    TestCase::read("13_02_strict_config").run_and_compare_result();
+    // no such difficulty
+    TestCase::read("13_03_invalid_difficulty").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_14_hard_all_rows() {
+    // the green of the first row still binds after the second one
+    TestCase::read("14_01_hard_all_rows").run_and_compare_result();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_15_alphabets() {
+    // the spanish alphabet, with Ñ printed after N on the keyboard
+    TestCase::read("15_01_spanish").run_and_compare_result();
+    // no alphabet given, the german letters follow the english ones
+    TestCase::read("15_02_german").run_and_compare_result();
+    // a word list spelled out of the alphabet given
+    TestCase::read("15_03_out_of_alphabet").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_16_keyboard_layout() {
+    // a built-in layout, the non interactive keyboard keeps the alphabet order
+    TestCase::read("16_01_keyboard_layout").run_and_compare_result();
+    // a layout file chosen in the config file
+    TestCase::read("16_02_custom_layout").run_and_compare_result();
+    // a layout file that doesn't exist
+    TestCase::read("16_03_invalid_layout").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_17_daily() {
+    // an archived daily puzzle, its date is saved with the round
+    TestCase::read("17_01_daily_archive").run_and_compare_game_state();
+    // the puzzle of a day can only be played once
+    TestCase::read("17_02_daily_replayed").run_and_expect_exit();
+    // the daily puzzle has no random answer
+    TestCase::read("17_03_daily_conflict").run_and_expect_exit();
+    // there is no puzzle before the epoch
+    TestCase::read("17_04_date_before_epoch").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_18_share() {
+    // the emoji block of a daily puzzle in the hard mode
+    TestCase::read("18_01_share").run_and_compare_result();
+    // the ASCII block of a failed round on two boards
+    TestCase::read("18_02_share_ascii").run_and_compare_result();
+    // sharing turned on in the config file, unlimited guesses
+    TestCase::read("18_03_share_config").run_and_compare_result();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_19_challenge() {
+    // the code of a challenge in the hard mode
+    TestCase::read("19_01_challenge_create").run_and_compare_result();
+    // the challenge played from its code, the hard mode goes along with it
+    TestCase::read("19_02_challenge_play").run_and_compare_result();
+    // a code with its last digit changed
+    TestCase::read("19_03_challenge_tampered").run_and_expect_exit();
+    // a code played with other word lists than it was made with
+    TestCase::read("19_04_challenge_other_lists").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_20_timed() {
+    // the times of the timed rounds stay in the state file, the non interactive rounds are not timed
+    TestCase::read("20_01_times_kept").run_and_compare_game_state();
+    // a speedrun plays its own answers
+    TestCase::read("20_02_speedrun_conflict").run_and_expect_exit();
+    // the minutes of a time attack are a positive number
+    TestCase::read("20_03_invalid_time_attack").run_and_expect_exit();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_21_statistics() {
+    // the guess distribution and the streaks counted over the saved rounds, the round without an answer left out
+    TestCase::read("21_01_streak").run_and_compare_game_state();
+    // the streaks and the guess distribution shown by "stats", the game keeps to its two lines
+    TestCase::read("21_02_streak_stats").run_and_compare_result();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_22_state_versions() {
+    // a file without a version read and saved in the latest one, the new round with how it was played
+    TestCase::read("22_01_state_upgraded").run_and_compare_game_state();
+    // a file of a version newer than the program
+    TestCase::read("22_02_state_newer_version").run_and_expect_exit();
+    // the rounds played in a non interactive terminal are timestamped too
+    TestCase::read("22_03_state_timestamps").run_and_check_timestamps();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_23_autosave() {
+    // a state file that doesn't exist yet is started clean and written when the round is over
+    TestCase::read("23_01_state_created").run_and_compare_new_game_state();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_24_resume() {
+    // the round left in the middle continued, its guesses played again first
+    TestCase::read("24_01_resume_continued").run_and_compare_game_state();
+    // the round left in the middle dropped, a new one played
+    TestCase::read("24_02_resume_dropped").run_and_compare_game_state();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_25_exit_codes() {
+    // a mode combination the arguments don't allow
+    TestCase::read("25_01_args_code").run_and_expect_code(2);
+    // a value of the wrong type in the config file
+    TestCase::read("25_02_config_code").run_and_expect_code(3);
+    // a word appearing twice in the final set
+    TestCase::read("25_03_word_list_code").run_and_expect_code(4);
+    // a state file cut off in the middle
+    TestCase::read("25_04_state_code").run_and_expect_code(5);
+    // a code that is not a challenge
+    TestCase::read("25_05_challenge_code").run_and_expect_code(6);
+    // the input ended before a guess was made
+    TestCase::read("25_06_input_code").run_and_expect_code(8);
+    // a key appearing twice on the keyboard layout
+    TestCase::read("25_07_layout_code").run_and_expect_code(9);
+}
+
+#[test]
+#[timeout(1000)]
+fn test_26_subcommands() {
+    // the sizes and the identity of the built-in word lists
+    TestCase::read("26_01_dict").run_and_compare_result();
+    // the answers left by the clues and the guess giving the most information
+    TestCase::read("26_02_solve").run_and_compare_result();
+    // a failed round of a state file colored again guess by guess
+    TestCase::read("26_03_replay").run_and_compare_result();
+    // the statistics of a state file, read without playing
+    TestCase::read("26_04_stats").run_and_compare_result();
+    // the conflicts of the arguments are checked by the subcommand too
+    TestCase::read("26_05_play_conflict").run_and_expect_code(2);
+    // a clue of the wrong length
+    TestCase::read("26_06_solve_bad_clue").run_and_expect_code(2);
+    // a guess kept in the state file shorter than its answer
+    TestCase::read("26_07_replay_bad_guess").run_and_compare_error(5);
+}
+
+#[test]
+#[timeout(1000)]
+fn test_27_toml_config() {
+    // the config of 08_01_config_file written in TOML plays the same rounds
+    TestCase::read("27_01_toml_config").run_and_compare_result();
+    // the merged config, the seed of the mode in the file given on the command line
+    TestCase::read("27_02_config_sources").run_and_compare_result();
+    // wordle/config.toml in $XDG_CONFIG_HOME loaded without --config
+    TestCase::read("27_03_discovered_config")
+        .with_config_home("tests/data/27_03_config_home")
+        .run_and_compare_result();
+    // a value of the wrong type in a TOML config
+    TestCase::read("27_04_bad_toml").run_and_expect_code(3);
+    // the answer of a challenge, "hello", never shown
+    TestCase::read("27_05_challenge_hidden").run_and_compare_result();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_28_config_validation() {
+    // a misspelled key, with the keys it is likely a typo of
+    TestCase::read("28_01_unknown_key").run_and_compare_error(3);
+    // a value of the wrong type, with the type expected
+    TestCase::read("28_02_wrong_type").run_and_compare_error(3);
+    // every pair that doesn't go together, each key with where it was set
+    TestCase::read("28_03_conflicts").run_and_compare_error(3);
+    // a speedrun of no rounds
+    TestCase::read("28_04_zero_speedrun").run_and_compare_error(3);
+    // a seed without a mode it shuffles the answers for
+    TestCase::read("28_05_seed_alone").run_and_compare_error(3);
+    // a file that is not TOML, with the line it breaks on
+    TestCase::read("28_06_syntax").run_and_compare_error(3);
+    // the answer in the file with random on the command line
+    TestCase::read("28_07_word_random").run_and_compare_error(3);
+}
+
+#[test]
+#[timeout(1000)]
+fn test_29_profiles() {
+    // a profile inheriting another, both laid over the keys at the top
+    TestCase::read("29_01_profile_inherits").run_and_compare_result();
+    // the state file at the top taken as the pattern of the one of the profile
+    TestCase::read("29_02_profile_state").run_and_compare_result();
+    // the statistics of the state file of a profile
+    TestCase::read("29_03_profile_stats").run_and_compare_result();
+    // a profile not in the file, with the ones that are
+    TestCase::read("29_04_unknown_profile").run_and_compare_error(3);
+    // profiles inheriting in a loop, found though another one is played with
+    TestCase::read("29_05_profile_loop").run_and_compare_error(3);
+    // the dates written bare in a profile, taken as they are at the top
+    TestCase::read("29_06_profile_dates").run_and_compare_result();
+}
+
+#[test]
+#[timeout(1000)]
+fn test_30_lang() {
+    // a locale taken as the language, shown with where it comes from
+    TestCase::read("30_01_lang_source").run_and_compare_result();
+    // the output of a non interactive terminal is never translated
+    TestCase::read("30_02_notty_untranslated").run_and_compare_result();
+    // a language there are no messages in
+    TestCase::read("30_03_unknown_lang").run_and_compare_error(2);
+    // a language there are no messages in, set in the config file
+    TestCase::read("30_04_lang_in_file").run_and_compare_error(3);
+    // a subcommand taking the language, its non interactive output the same
+    TestCase::read("30_05_replay_untranslated").run_and_compare_result();
+}
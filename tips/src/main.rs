@@ -1,99 +1,135 @@
-pub mod builtin_words;
-pub mod common;
 pub mod tty_true;
-use crate::{
-    common::{args, config, random, stateload, statesave, word, Config, FINALSET},
-    tty_true::{go_on_tty, quantify1, stats_tty, test, tty},
+use {
+    crate::tty_true::{go_on_tty, quantify1, stats_tty, tty},
+    wordle_core::{
+        alphabet::upper,
+        common::{
+            config, daily, details, game_args, keys, level, pick, place, random, stateload,
+            statesave, word, wordsets, Config, Details, Outcome,
+        },
+        dictionary::Dictionary,
+        error::Error,
+        locale::{Lang, Msg},
+    },
 };
 
 /*
 function: to play a round of game
 input:  ans: the answer of this
         arg: arguments from command line and config file (already processed)
+        dict: the word lists of the game
         guesses: where stores every valid guesses the player inputs
         results: where stores the results of every round of games, including win/fail, the times player tried
 output: true to play another game, false to quit
 */
 fn round(
     arg: &Config,
+    dict: &Dictionary,
     ans: &str,
     guesses: &mut Vec<String>,
-    results: &mut Vec<Option<(Option<bool>, Option<usize>)>>,
-) -> Result<bool, String> {
+    results: &mut Vec<Outcome>,
+) -> Result<bool, Error> {
     let mut next = false;
+    let lang = Lang::choose(&arg.lang);
     tty(
         ans,
-        arg.difficult.is_some() && arg.difficult.unwrap(),
+        level(arg),
+        arg.max_guesses.unwrap(),
+        dict,
+        &keys(arg, dict)?,
         guesses,
         results,
+        lang,
     )?;
     if arg.stats.is_some() && arg.stats.unwrap() {
-        stats_tty(&guesses, &results);
+        stats_tty(guesses, results, lang);
     } // show statistics
-    if !arg.word.is_some() {
-        next = go_on_tty()?; // another round?
+    if arg.word.is_none() {
+        next = go_on_tty(lang)?; // another round?
     }
     Ok(next)
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/*
+function: to solve the game the arguments ask for
+input: None
+output: None, the error the solver stops with
+*/
+fn run() -> Result<(), Error> {
     let mut guesses: Vec<String> = vec![];
-    let mut results: Vec<Option<(Option<bool>, Option<usize>)>> = vec![];
-    let mut answers: Vec<Option<String>> = vec![];
-    let arg = config(&args()?)?;
-    /*test();*/
-    println!("Suggested guesses: ");
-    let suggested = quantify1(10); // suggestted first guess
-    for i in 0..10 {
-        println!("{}: {:.4}", suggested[i].0, suggested[i].1);
+    let mut results: Vec<Outcome> = vec![];
+    let mut answers: Vec<Vec<String>> = vec![];
+    let mut records: Vec<Details> = vec![];
+    let arg = config(&game_args("tips"))?;
+    let dict = wordsets(&arg)?;
+    if arg.boards.unwrap() != 1 {
+        return Err(Error::Args(String::from("the tips solve a single board")));
+    }
+    if arg.absurdle.is_some() && arg.absurdle.unwrap() {
+        return Err(Error::Args(String::from("the tips need a fixed answer")));
+    }
+    if daily(&arg, &dict)?.is_some() {
+        return Err(Error::Args(String::from(
+            "the daily puzzle is played without tips",
+        )));
+    }
+    if arg.speedrun.is_some() || arg.time_attack.is_some() {
+        return Err(Error::Args(String::from(
+            "the timed modes are played without tips",
+        )));
+    }
+    /*tty_true::test(&dict);*/
+    let lang = Lang::choose(&arg.lang);
+    println!("{}", lang.text(Msg::SuggestedFirst));
+    let suggested = quantify1(10, &dict); // suggestted first guess
+    for (word, infor) in suggested.iter() {
+        println!("{}: {:.4}", word, infor);
     }
     if arg.random.is_some() && arg.random.unwrap() {
         // when answers are random
-        let list = 0..unsafe { FINALSET.len() };
+        let list = 0..dict.finals().len();
         let mut list = list.collect::<Vec<usize>>();
         let mut sub = random(&arg.day, &arg.seed, &mut list);
-        if arg.state.is_some() {
+        if let Some(state) = &arg.state {
             // load information from json file
-            stateload(&arg.state, &mut answers, &mut guesses, &mut results)?;
+            if !stateload(
+                &arg.state,
+                &mut answers,
+                &mut records,
+                &mut guesses,
+                &mut results,
+                &mut None, // a round left in the middle is continued in the game, not here
+            )? {
+                eprintln!("{} does not exist yet, a new state file is started", state);
+            }
         }
         loop {
-            let mut ans = unsafe { &FINALSET[list[sub]] }.to_string();
-            ans = ans.to_ascii_uppercase();
-            if answers.contains(&Some(ans.clone())) {
-                // ensure the random answers don't duplicate
-                if answers.len() == list.len() {
-                    panic!("All answers in the finalset played");
-                }
-                sub = (sub + 1) % list.len(); //try next answer
-                continue;
-            }
-            answers.push(Some(ans.clone().to_ascii_uppercase()));
-            let next = round(&arg, &ans, &mut guesses, &mut results)?; // another round?
-            if next {
-                sub = (sub + 1) % unsafe { FINALSET.len() }; // change sub to get a new random answer
-            } else {
+            // the random answers don't duplicate, sub moves on to get a new random answer
+            let ans = pick(&list, &mut sub, &dict, &answers, 1)?;
+            answers.push(ans.clone());
+            records.push(Details {
+                day: place(&list, &dict, &ans[0]),
+                ..details(&arg, &dict)
+            });
+            let next = round(&arg, &dict, &ans[0], &mut guesses, &mut results)?; // another round?
+            statesave(&arg.state, &answers, &records, &guesses, &results, None)?; // autosaved every round
+            if !next {
                 break;
             }
         }
-        if arg.state.is_some() {
-            //save information to json file
-            statesave(&arg.state, &answers, &guesses, &results)?;
-        }
     } else {
         if arg.word.is_some() {
             // when answer is decided in the arguments
-            let mut ans = word(&arg.word)?;
-            ans = ans.to_ascii_uppercase();
-            answers.push(Some(ans.clone().to_ascii_uppercase()));
-            round(&arg, &ans, &mut guesses, &mut results)?;
+            let ans = upper(&word(&arg.word, &dict, 1)?[0]);
+            answers.push(vec![ans.clone()]);
+            round(&arg, &dict, &ans, &mut guesses, &mut results)?;
         } else {
             // when answers are from input
             loop {
-                println!("Input the answer:");
-                let mut ans = word(&arg.word)?;
-                ans = ans.to_ascii_uppercase();
-                answers.push(Some(ans.clone().to_ascii_uppercase()));
-                let next = round(&arg, &ans, &mut guesses, &mut results)?;
+                println!("{}", lang.text(Msg::InputAnswer));
+                let ans = upper(&word(&arg.word, &dict, 1)?[0]);
+                answers.push(vec![ans.clone()]);
+                let next = round(&arg, &dict, &ans, &mut guesses, &mut results)?;
                 if !next {
                     break;
                 }
@@ -102,3 +138,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     Ok(())
 }
+
+/*
+function: to print the error the solver stops with, and exit with its code
+input: None
+output: None
+*/
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        std::process::exit(err.code());
+    }
+}
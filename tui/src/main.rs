@@ -1,26 +1,41 @@
-pub mod builtin_words;
 pub mod common;
 
 use {
-    common::{
-        args, change_keyboard, char2border, char2color, char2location, color, config, cut,
-        diffcult, exist, random, stateload, statesave, stats, Config, ACCEPTSET, FINALSET, LENGTH,
-        LIMIT,
-    },
+    common::{change_keyboard, char2location, cut, select2border, tile2color},
     crossterm::{
         event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
         execute,
-        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+        terminal::{
+            disable_raw_mode, enable_raw_mode, is_raw_mode_enabled, EnterAlternateScreen,
+            LeaveAlternateScreen,
+        },
+    },
+    std::{
+        io,
+        time::{Duration, Instant},
+        vec,
     },
-    std::{io, time::Duration, vec},
     tui::{
         backend::{Backend, CrosstermBackend},
         layout::{Alignment, Constraint, Direction, Layout},
         style::{Color, Modifier, Style},
         text::Span,
-        widgets::{Block, BorderType, Borders, Paragraph},
+        widgets::{BarChart, Block, BorderType, Borders, Paragraph},
         Frame, Terminal,
     },
+    wordle_core::{
+        alphabet::{upper, upper_letter},
+        common::{
+            budget, clock, config, daily, details, fits, game_args, grid, keys, level, now, pick,
+            place, random, resumable, resumed, score, seconds, share, sharing, stateload,
+            statesave, stats, unplayed, used_up, word, wordsets, Config, Details, Difficulty, Mode,
+            Outcome, Stats, Unfinished, DEFAULT_SEED, UNLIMITED,
+        },
+        dictionary::Dictionary,
+        error::Error,
+        feedback::{Feedback, Tile},
+        locale::{Lang, Msg},
+    },
 };
 
 struct Information {
@@ -28,95 +43,230 @@ struct Information {
     pub mode: String,
     pub result: String,
     pub preference: String,
+    pub distribution: Vec<(String, usize)>, // the bars of the guess distribution, once a round is over
+    pub lang: Lang,                         // the language of the messages and the titles
 }
 struct History {
     pub guesses: Vec<Vec<char>>,
-    pub colors: Vec<Vec<char>>,
+    pub colors: Vec<Vec<Option<Feedback>>>, // None for the boards solved before the guess
     pub buf: Vec<char>,
+    pub length: usize,
+    pub limit: usize,
+    pub solved: Vec<Option<usize>>, // the guess each board was solved at
+    pub keys: Vec<Vec<char>>,       // the rows of the keyboard
+    pub start: Instant,             // when the round started
+    pub time: Option<f64>,          // the seconds the round took, once it is over
+    pub record: Details,            // how the round is played, kept in the state file
 }
+struct Sharing {
+    pub auto: bool,           // share every round when it ends, not only on 'S'
+    pub ascii: bool,          // letters in place of the emoji
+    pub file: Option<String>, // the file the blocks are appended to
+    pub day: Option<usize>,   // the day of the daily puzzle, the number of the puzzle
+    pub blocks: Vec<String>,  // the blocks printed when the terminal is given back
+}
+
+static ROWS: usize = 8; // the most guesses shown at once
 
 /*
     the basic structure of the main function and the methods to deal with blocks cite
     the contents on the website: "https://www.cnblogs.com/xueweihan/p/15992139.html",
     based on which I developed the logic and patterns for my tui
 */
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn run() -> Result<(), Error> {
     // set terminal
-    let arg = config(&args()?)?;
+    let arg = config(&game_args("tui"))?;
+    let dict = wordsets(&arg)?;
+    let keys = keys(&arg, &dict)?;
+    let lang = Lang::choose(&arg.lang);
+    let mut guesses: Vec<String> = vec![];
+    let mut results: Vec<Outcome> = vec![];
+    let mut answers: Vec<Vec<String>> = vec![];
+    let mut records: Vec<Details> = vec![];
+    let mut notice = String::new();
+    let mut unfinished = None;
+    if arg.state.is_some() {
+        // load from json
+        if !stateload(
+            &arg.state,
+            &mut answers,
+            &mut records,
+            &mut guesses,
+            &mut results,
+            &mut unfinished,
+        )? {
+            notice = lang.text(Msg::NewStateFile);
+        }
+    }
+    if arg.speedrun.is_some() || arg.time_attack.is_some() {
+        return Err(Error::Args(String::from(
+            "the timed modes are played in the wordle command",
+        )));
+    }
+    let boards = arg.boards.unwrap();
+    let today = daily(&arg, &dict)?;
+    let assigned = match (&arg.word, &today) {
+        (Some(_), _) => Some(word(&arg.word, &dict, boards)?),
+        (None, Some((today, day))) => {
+            unplayed(&records, today)?;
+            let mut list = (0..dict.finals().len()).collect::<Vec<usize>>();
+            let mut sub = random(&Some(*day), &arg.seed.or(Some(DEFAULT_SEED)), &mut list);
+            Some(pick(&list, &mut sub, &dict, &[], boards)?) // the same for everyone
+        }
+        (None, None) => None,
+    }; // checked before the terminal is taken over
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
+    let continued = match unfinished {
+        Some(unfinished)
+            if resumable(&unfinished, &dict) && offer(&mut terminal, &unfinished, lang)? =>
+        {
+            Some(unfinished)
+        }
+        _ => None, // dropped by the next save
+    };
+    let (arg, today, assigned) = match &continued {
+        Some(unfinished) if unfinished.details.mode == Some(Mode::Absurdle) => (
+            resumed(&arg, unfinished),
+            None,
+            Some(dict.finals().to_vec()), // every word is a candidate again
+        ),
+        Some(unfinished) => (
+            resumed(&arg, unfinished),
+            None,
+            Some(unfinished.answers.clone()),
+        ),
+        None => (arg, today, assigned),
+    }; // the round continued is played the way it was started
+    let boards = arg.boards.unwrap();
     // initialize
-    let mut guesses: Vec<String> = vec![];
-    let mut results: Vec<Option<(Option<bool>, Option<usize>)>> = vec![];
-    let mut answers: Vec<Option<String>> = vec![];
-    let mut hard = false;
+    let level = level(&arg);
+    let absurd = arg.absurdle.is_some() && arg.absurdle.unwrap();
     let mut history = History {
         guesses: vec![],
         colors: vec![],
         buf: vec![],
+        length: arg.length.unwrap(),
+        limit: budget(arg.max_guesses.unwrap(), boards),
+        solved: vec![None; boards],
+        keys,
+        start: Instant::now(),
+        time: None,
+        record: match &continued {
+            Some(unfinished) => unfinished.details.clone(),
+            None => Details {
+                day: today.as_ref().map(|(_, day)| *day),
+                date: today.as_ref().map(|(today, _)| today.clone()),
+                ..details(&arg, &dict)
+            },
+        },
     };
     let mut information = Information {
-        message: String::from(""),
+        message: notice,
         mode: String::from(""),
         result: String::from(""),
         preference: String::from(""),
+        distribution: vec![],
+        lang,
     };
-    let mut key_board = vec![
-        vec!['X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X'],
-        vec!['X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X'],
-        vec!['X', 'X', 'X', 'X', 'X', 'X', 'X'],
-    ];
-    if arg.state.is_some() {
-        // load from json
-        stateload(&arg.state, &mut answers, &mut guesses, &mut results)?;
+    let mut sharing = Sharing {
+        auto: sharing(&arg),
+        ascii: arg.share_ascii.is_some() && arg.share_ascii.unwrap(),
+        file: arg.share_file.clone(),
+        day: today.as_ref().map(|(_, day)| *day),
+        blocks: vec![],
+    };
+    let key_board: Vec<Vec<Option<Tile>>> = history
+        .keys
+        .iter()
+        .map(|row| vec![None; row.len()])
+        .collect();
+    let mut key_boards = vec![key_board; history.solved.len()]; // a keyboard for each board
+    information.mode = lang.text(Msg::Mode(level));
+    if absurd {
+        information.mode += &lang.text(Msg::AbsurdleMode);
     }
-    if arg.difficult.is_some() && arg.difficult.unwrap() {
-        //difficult?
-        hard = true;
-        information.mode = String::from("Difficult mode");
-    } else {
-        information.mode = String::from("Simple mode");
+    if let Some((today, _)) = &today {
+        information.mode += &lang.text(Msg::DailyOf(today));
+    }
+    if continued.is_some() {
+        information.mode += &lang.text(Msg::ContinuedMode);
     }
-    let mut ans = String::new();
     // run the game
-    if arg.word.is_some() {
-        // word asigned, one round
-        if exist(&arg.word.as_ref().unwrap(), unsafe { &FINALSET }) {
-            ans = String::from(arg.word.as_ref().unwrap().to_ascii_uppercase());
-            answers.push(Some(ans.clone()));
-        }
+    if let Some(ans) = assigned {
+        // word asigned, the daily puzzle or a round continued, one round
+        let mut ans: Vec<String> = ans.iter().map(|ans| upper(ans)).collect();
+        let replay = match &continued {
+            Some(unfinished) => unfinished.guesses.clone(),
+            None => vec![],
+        };
         single_game(
-            &ans.to_string(),
-            hard,
+            &mut ans,
+            &replay,
+            level,
+            absurd,
+            &dict,
             &mut guesses,
             &mut results,
             &mut terminal,
             &mut history,
             &mut None,
             &mut information,
-            &mut key_board,
+            &mut key_boards,
+            &mut sharing,
         )?;
+        answers.push(ans); // the word committed to in the absurdle mode
+        records.push(history.record.clone());
     } else {
         // random word
         go_on_game(
             &arg,
             &mut answers,
-            hard,
+            &mut records,
+            level,
+            absurd,
+            &dict,
             &mut guesses,
             &mut results,
             &mut terminal,
             &mut history,
             &mut None,
             &mut information,
-            &mut key_board,
+            &mut key_boards,
+            &mut sharing,
         )?;
     }
     if arg.state.is_some() {
         //save to json
-        statesave(&arg.state, &mut answers, &mut guesses, &mut results)?;
+        let unfinished = if answers.len() > results.len() && !history.guesses.is_empty() {
+            Some(Unfinished {
+                answers: if absurd {
+                    vec![] // replayed from all the words
+                } else {
+                    answers[answers.len() - 1].clone()
+                },
+                guesses: history
+                    .guesses
+                    .iter()
+                    .map(|guess| guess.iter().collect())
+                    .collect(),
+                max_guesses: arg.max_guesses.unwrap(),
+                details: history.record.clone(),
+            })
+        } else {
+            None
+        }; // left with 'ESC' in the middle of a round
+        statesave(
+            &arg.state,
+            &answers,
+            &records,
+            &guesses,
+            &results,
+            unfinished.as_ref(),
+        )?;
     }
     // end of the game, return terminal
     disable_raw_mode()?;
@@ -126,86 +276,266 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         DisableMouseCapture
     )?;
     terminal.show_cursor()?;
+    for block in sharing.blocks.iter() {
+        print!("{}", block); // the terminal is back to show them
+    }
     Ok(())
 }
+
+/*
+function: to print the error the game stops with, and exit with its code,
+            the terminal is given back first if the game stopped on it
+input: None
+output: None
+*/
+fn main() {
+    if let Err(err) = run() {
+        if is_raw_mode_enabled().unwrap_or(false) {
+            let _ = disable_raw_mode();
+            let _ = execute!(
+                io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                crossterm::cursor::Show
+            );
+        }
+        eprintln!("{}", err);
+        std::process::exit(err.code());
+    }
+}
+
+/*
+function: to write the statistics of the rounds for the player
+input: append: the statistics
+        lang: the language of the text
+output: the text
+*/
+fn result(append: &Stats, lang: Lang) -> String {
+    let mut text = lang.text(Msg::Results(
+        append.wins,
+        append.losses,
+        append.win_rate(),
+        append.average,
+        append.current_streak,
+        append.max_streak,
+    ));
+    if let (Some(best), Some(average)) = (append.best_time, append.average_time) {
+        text += &lang.text(Msg::BestTime(&clock(best), &clock(average)));
+    }
+    text
+}
+
+/*
+function: to share the result of the round just ended
+input: history: the inputs and current state of the round
+        results: where stores the results of every round of games, the last one is shared
+        level: the difficulty
+        sharing: how the round is shared
+        lang: the language of the message
+output: the message for the player
+*/
+fn share_round(
+    history: &History,
+    results: &[Outcome],
+    level: Difficulty,
+    sharing: &mut Sharing,
+    lang: Lang,
+) -> String {
+    let number = sharing.day.unwrap_or(results.len()); // the number of the round if not daily
+    let block = grid(
+        number,
+        &history.colors,
+        &results[results.len() - 1],
+        level,
+        sharing.ascii,
+    );
+    match &sharing.file {
+        Some(file) => match share(&sharing.file, &block) {
+            Ok(()) => lang.text(Msg::SharedTo(file)),
+            Err(err) => format!("{}. ", err),
+        },
+        None => {
+            sharing.blocks.push(block);
+            lang.text(Msg::SharedOnExit)
+        }
+    }
+}
+/*
+function: to offer to continue the round left in the middle in the state file
+input: terminal: the terminal
+        unfinished: the round left in the middle
+        lang: the language of the offer
+output: true to continue it, false to drop it
+*/
+fn offer<B: Backend>(
+    terminal: &mut Terminal<B>,
+    unfinished: &Unfinished,
+    lang: Lang,
+) -> io::Result<bool> {
+    let text = lang.text(Msg::Offer(&unfinished.guesses));
+    let title = lang.text(Msg::UnfinishedTitle);
+    loop {
+        terminal.draw(|f| {
+            let block = Block::default()
+                .title(title.as_str())
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+            let paragraph = Paragraph::new(text.as_str())
+                .block(block)
+                .alignment(Alignment::Center);
+            let area = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+                .split(f.size())[0];
+            f.render_widget(paragraph, area);
+        })?;
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char('y') => break Ok(true),
+                KeyCode::Char('n') | KeyCode::Esc => break Ok(false),
+                _ => {}
+            }
+        }
+    }
+}
+
 /*
 function: to play a single round of game
-input: ans: answer for this round of game
-        hard: difficult mode?
+input: ans: the answer of each board for this round of game, all the candidates in the absurdle mode
+        replay: the guesses made before the round was left in the middle, played first
+        level: the difficulty
+        absurd: the absurdle mode? the answer is committed to when the round ends
+        dict: the word lists of the game
         guesses: where stores all the valid guesses the player inputs
         results: where stores the results of every round of games, including win/fail, the times player tried
         terminal: the terminal
         history: the inputs and current state of a round of game
         inputs: last keycode
         information: the message and statistic for the player
-        keyboard: the current state of colors of keyboard
+        keyboards: the current state of colors of the keyboard of each board
+        sharing: how the result is shared
 output: None
 */
+#[allow(clippy::too_many_arguments)]
 fn single_game<B: Backend>(
-    ans: &String,
-    hard: bool,
+    ans: &mut Vec<String>,
+    replay: &[String],
+    level: Difficulty,
+    absurd: bool,
+    dict: &Dictionary,
     guesses: &mut Vec<String>,
-    results: &mut Vec<Option<(Option<bool>, Option<usize>)>>,
+    results: &mut Vec<Outcome>,
     terminal: &mut Terminal<B>,
     history: &mut History,
     input: &mut Option<char>,
     information: &mut Information,
-    keyboard: &mut Vec<Vec<char>>,
+    keyboards: &mut [Vec<Vec<Option<Tile>>>],
+    sharing: &mut Sharing,
 ) -> io::Result<()> {
     let mut total = 0; //number of guesses
     let mut exit = false;
+    history.start = Instant::now();
+    history.record.started.get_or_insert_with(now); // when it was first started if continued
+    for guess in replay {
+        // valid when they were made
+        guesses.push(guess.clone());
+        let letters = guess.chars().collect::<Vec<char>>();
+        total += 1;
+        let color = score(ans, absurd, guess, total, &mut history.solved);
+        for (board, keyboard) in color.iter().zip(keyboards.iter_mut()) {
+            if let Some(board) = board {
+                change_keyboard(&letters, board, keyboard, &history.keys);
+            }
+        }
+        history.guesses.push(letters);
+        history.colors.push(color);
+    }
     loop {
-        terminal.draw(|f| ui(f, history, input, information, keyboard))?;
+        terminal.draw(|f| ui(f, history, input, information, keyboards))?;
         if crossterm::event::poll(Duration::from_secs(1))? {
             if let Event::Key(key) = event::read()? {
                 match key.code {
-                    KeyCode::Char(ch) => {
-                        if history.buf.len() != LENGTH - 1 {
-                            //buf not full
-                            if input.is_some() {
-                                // input not the first letter of a word
-                                history.buf.push(input.unwrap().to_ascii_uppercase());
-                            }
-                            *input = Some(ch.to_ascii_uppercase()); // the first letter of a word
+                    KeyCode::Char(ch)
+                        if history.buf.len() != history.length - 1
+                            && dict.alphabet().contains(ch) =>
+                    {
+                        //buf not full
+                        if input.is_some() {
+                            // input not the first letter of a word
+                            history.buf.push(upper_letter(input.unwrap()));
                         }
+                        *input = Some(upper_letter(ch)); // the first letter of a word
                     }
                     KeyCode::Enter => {
-                        if input.is_some() && history.buf.len() == LENGTH - 1 {
+                        if input.is_some() && history.buf.len() == history.length - 1 {
                             //input is a char, together with buf to form a guess
-                            history.buf.push(input.unwrap().to_ascii_uppercase());
+                            history.buf.push(upper_letter(input.unwrap()));
                         }
                         // a guess is formed
-                        if history.buf.len() == LENGTH {
+                        if history.buf.len() == history.length {
                             let guess = history.buf.iter().collect::<String>(); //get guess
-                            if exist(&guess, unsafe { &ACCEPTSET }) == false {
-                                information.message = String::from("INVALID");
-                            } else if hard
-                                && total > 0
-                                && !diffcult(
+                            let checked = if dict.is_acceptable(&guess) {
+                                fits(
+                                    level,
                                     &guess,
-                                    &guesses[guesses.len() - 1],
-                                    &history.colors[total - 1],
+                                    &guesses[guesses.len() - total..],
+                                    &history.colors,
                                 )
-                            {
-                                information.message = String::from("INVALID");
+                                .map_err(|rule| information.lang.text(Msg::Rule(rule)))
+                            } else {
+                                Err(information.lang.text(Msg::NotInList))
+                            };
+                            if let Err(reason) = checked {
+                                information.message = information.lang.text(Msg::Invalid(&reason));
+                                history
+                                    .record
+                                    .invalid
+                                    .get_or_insert_with(Vec::new)
+                                    .push(guess);
                             } else {
                                 guesses.push(guess.clone());
                                 history
                                     .guesses
                                     .push(guess.clone().chars().collect::<Vec<char>>());
                                 total += 1;
-                                history.colors.push(color(&ans, &guess));
-                                change_keyboard(&history.buf, &color(&ans, &guess), keyboard);
+                                let color = score(ans, absurd, &guess, total, &mut history.solved);
+                                for (board, keyboard) in color.iter().zip(keyboards.iter_mut()) {
+                                    if let Some(board) = board {
+                                        change_keyboard(
+                                            &history.buf,
+                                            board,
+                                            keyboard,
+                                            &history.keys,
+                                        );
+                                    }
+                                } // the boards solved before the guess left as they were
+                                history.colors.push(color);
                             } //guess processed
                             history.buf = vec![];
                             *input = None;
-                            if guess == *ans {
-                                information.message = String::from(format!("Correct! "));
-                                results.push(Some((Some(true), Some(total))));
+                            if history.solved.iter().all(|board| board.is_some()) {
+                                history.time = Some(seconds(history.start));
+                                history.record.finished = Some(now());
+                                information.message = information.lang.text(Msg::CorrectShort);
+                                results.push(Some((
+                                    Some(true),
+                                    Some(total),
+                                    history.limit,
+                                    history.time,
+                                )));
                                 break;
-                            } else if total == LIMIT {
-                                information.message = String::from(format!("Fail at {}. ", ans));
-                                results.push(Some((Some(false), Some(LIMIT))));
+                            } else if used_up(total, history.limit) {
+                                history.time = Some(seconds(history.start));
+                                history.record.finished = Some(now());
+                                ans.truncate(history.solved.len()); // the absurdle mode commits to a word left
+                                information.message =
+                                    information.lang.text(Msg::FailAt(&ans.join(" ")));
+                                results.push(Some((
+                                    Some(false),
+                                    Some(total),
+                                    history.limit,
+                                    history.time,
+                                )));
                                 break;
                             }
                         }
@@ -222,31 +552,34 @@ fn single_game<B: Backend>(
             }
         }
     }
-    if exit {
-        return Ok(());
-    } else {
+    if !exit {
         // regular game over
-        let ((win, lose, rounds), preference) = stats(&guesses, &results);
-        information.result = format!(
-            "win: {} / lose: {}    average trail when winning: {:.2}",
-            win, lose, rounds
-        );
+        let append = stats(guesses, results);
+        information.result = result(&append, information.lang);
+        information.distribution = append.bars();
         let mut text = String::new();
-        for i in 0..preference.len() {
-            text += &(format!("{}: {};  ", preference[i].0, preference[i].1));
+        for (word, times) in append.words.iter() {
+            text += &(format!("{}: {};  ", word, times));
         }
         information.preference = text;
+        if sharing.auto {
+            information.message += &share_round(history, results, level, sharing, information.lang);
+        }
         loop {
             // draw the final outcome
-            terminal.draw(|f| ui(f, history, input, information, keyboard))?;
+            terminal.draw(|f| ui(f, history, input, information, keyboards))?;
             if crossterm::event::poll(Duration::from_secs(1))? {
                 if let Event::Key(key) = event::read()? {
                     match key.code {
                         KeyCode::Esc => {
                             break;
                         }
+                        KeyCode::Char('s') => {
+                            information.message =
+                                share_round(history, results, level, sharing, information.lang);
+                        }
                         _ => {
-                            information.message = String::from("click 'ESC' to exit");
+                            information.message = information.lang.text(Msg::ShareOrExit);
                         }
                     }
                 }
@@ -258,95 +591,146 @@ fn single_game<B: Backend>(
 
 /*
 function: to play sequent rounds of game
-input: ans: answer for this round of game
-        hard: difficult mode?
+input: arg: arguments from command line and config file (already processed)
+        answers: where stores the answers of every board of all the rounds
+        records: where stores how every round was played
+        level: the difficulty
+        absurd: the absurdle mode?
+        dict: the word lists of the game
         guesses: where stores all the valid guesses the player inputs
         results: where stores the results of every round of games, including win/fail, the times player tried
         terminal: the terminal
         history: the inputs and current state of a round of game
         inputs: last keycode
         information: the message and statistic for the player
-        keyboard: the current state of colors of keyboard
+        keyboards: the current state of colors of the keyboard of each board
+        sharing: how the results are shared
 output: None
 */
+#[allow(clippy::too_many_arguments)]
 fn go_on_game<B: Backend>(
     arg: &Config,
-    answers: &mut Vec<Option<String>>,
-    hard: bool,
+    answers: &mut Vec<Vec<String>>,
+    records: &mut Vec<Details>,
+    level: Difficulty,
+    absurd: bool,
+    dict: &Dictionary,
     guesses: &mut Vec<String>,
-    results: &mut Vec<Option<(Option<bool>, Option<usize>)>>,
+    results: &mut Vec<Outcome>,
     terminal: &mut Terminal<B>,
     history: &mut History,
     input: &mut Option<char>,
     information: &mut Information,
-    keyboard: &mut Vec<Vec<char>>,
-) -> io::Result<()> {
-    let list = 0..unsafe { FINALSET.len() };
+    keyboards: &mut [Vec<Vec<Option<Tile>>>],
+    sharing: &mut Sharing,
+) -> Result<(), Error> {
+    let list = 0..dict.finals().len();
     let mut list = list.collect::<Vec<usize>>();
     let mut sub = random(&arg.day, &arg.seed, &mut list);
     loop {
-        let ans = unsafe { &FINALSET[list[sub]] }
-            .to_string()
-            .to_ascii_uppercase();
-        answers.push(Some(ans.clone()));
-        information.message = String::from("New Game On");
+        // the random answers don't duplicate, sub moves on to get new random answers
+        history.record = details(arg, dict);
+        let mut ans = if absurd {
+            // every word is a candidate until the guesses narrow them down
+            dict.finals().iter().map(|word| upper(word)).collect()
+        } else {
+            let ans = pick(&list, &mut sub, dict, answers, history.solved.len())?;
+            answers.push(ans.clone());
+            history.record.day = place(&list, dict, &ans[0]);
+            ans
+        };
+        information.message += &information.lang.text(Msg::NewGame); // after the notice of a new state file
         let mut total = 0;
         let mut exit = false;
+        history.start = Instant::now();
+        history.record.started = Some(now());
         loop {
-            terminal.draw(|f| ui(f, history, input, information, keyboard))?;
+            terminal.draw(|f| ui(f, history, input, information, keyboards))?;
             if crossterm::event::poll(Duration::from_secs(1))? {
                 if let Event::Key(key) = event::read()? {
                     match key.code {
-                        KeyCode::Char(ch) => {
-                            if history.buf.len() != LENGTH - 1 {
-                                if input.is_some() {
-                                    history.buf.push(input.unwrap().to_ascii_uppercase());
-                                }
-                                *input = Some(ch.to_ascii_uppercase());
+                        KeyCode::Char(ch)
+                            if history.buf.len() != history.length - 1
+                                && dict.alphabet().contains(ch) =>
+                        {
+                            if input.is_some() {
+                                history.buf.push(upper_letter(input.unwrap()));
                             }
+                            *input = Some(upper_letter(ch));
                         }
                         KeyCode::Enter => {
                             // basically the same as single
-                            if input.is_some() && history.buf.len() == LENGTH - 1 {
-                                history.buf.push(input.unwrap().to_ascii_uppercase());
+                            if input.is_some() && history.buf.len() == history.length - 1 {
+                                history.buf.push(upper_letter(input.unwrap()));
                             }
-                            if history.buf.len() == LENGTH {
+                            if history.buf.len() == history.length {
                                 let guess = history.buf.iter().collect::<String>();
-                                if exist(&guess, unsafe { &ACCEPTSET }) == false {
-                                    information.message = String::from("INVALID");
-                                } else if hard
-                                    && total > 0
-                                    && !diffcult(
+                                let checked = if dict.is_acceptable(&guess) {
+                                    fits(
+                                        level,
                                         &guess,
-                                        &guesses[guesses.len() - 1],
-                                        &history.colors[total - 1],
+                                        &guesses[guesses.len() - total..],
+                                        &history.colors,
                                     )
-                                {
-                                    information.message = String::from("INVALID");
+                                    .map_err(|rule| information.lang.text(Msg::Rule(rule)))
+                                } else {
+                                    Err(information.lang.text(Msg::NotInList))
+                                };
+                                if let Err(reason) = checked {
+                                    information.message =
+                                        information.lang.text(Msg::Invalid(&reason));
+                                    history
+                                        .record
+                                        .invalid
+                                        .get_or_insert_with(Vec::new)
+                                        .push(guess);
                                 } else {
                                     guesses.push(guess.clone());
                                     history
                                         .guesses
                                         .push(guess.clone().chars().collect::<Vec<char>>());
                                     total += 1;
-                                    history.colors.push(color(&ans, &guess));
-                                    change_keyboard(&history.buf, &color(&ans, &guess), keyboard);
-                                    information.message = String::from("ACCEPTED");
+                                    let color =
+                                        score(&mut ans, absurd, &guess, total, &mut history.solved);
+                                    for (board, keyboard) in color.iter().zip(keyboards.iter_mut())
+                                    {
+                                        if let Some(board) = board {
+                                            change_keyboard(
+                                                &history.buf,
+                                                board,
+                                                keyboard,
+                                                &history.keys,
+                                            );
+                                        }
+                                    } // the boards solved before the guess left as they were
+                                    history.colors.push(color);
+                                    information.message = information.lang.text(Msg::Accepted);
                                 }
                                 history.buf = vec![];
                                 *input = None;
-                                if guess == ans {
-                                    information.message = String::from(format!(
-                                        "Correct! Input 'Y' to play again, click 'ESC' to exit."
-                                    ));
-                                    results.push(Some((Some(true), Some(total))));
+                                if history.solved.iter().all(|board| board.is_some()) {
+                                    history.time = Some(seconds(history.start));
+                                    history.record.finished = Some(now());
+                                    information.message = information.lang.text(Msg::CorrectAgain);
+                                    results.push(Some((
+                                        Some(true),
+                                        Some(total),
+                                        history.limit,
+                                        history.time,
+                                    )));
                                     break;
-                                } else if total == LIMIT {
-                                    information.message = String::from(format!(
-                                        "Fail at {}. Input 'Y' to play again, click 'ESC' to exit.",
-                                        ans
-                                    ));
-                                    results.push(Some((Some(false), Some(LIMIT))));
+                                } else if used_up(total, history.limit) {
+                                    history.time = Some(seconds(history.start));
+                                    history.record.finished = Some(now());
+                                    ans.truncate(history.solved.len()); // the absurdle mode commits to a word left
+                                    information.message =
+                                        information.lang.text(Msg::FailAtAgain(&ans.join(" ")));
+                                    results.push(Some((
+                                        Some(false),
+                                        Some(total),
+                                        history.limit,
+                                        history.time,
+                                    )));
                                     break;
                                 }
                             }
@@ -363,49 +747,69 @@ fn go_on_game<B: Backend>(
                 }
             }
         }
+        if absurd {
+            ans.truncate(history.solved.len());
+            answers.push(ans.clone()); // the word the absurdle mode committed to
+        }
+        records.push(history.record.clone());
         if exit {
             break;
         } else {
-            *keyboard = vec![
-                vec!['X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X'],
-                vec!['X', 'X', 'X', 'X', 'X', 'X', 'X', 'X', 'X'],
-                vec!['X', 'X', 'X', 'X', 'X', 'X', 'X'],
-            ]
-            .clone();
-            let ((win, lose, rounds), preference) = stats(&guesses, &results);
-            information.result = format!(
-                "win: {} / lose: {}    average trail when winning: {:.2}",
-                win, lose, rounds
-            );
+            // autosaved as soon as the round is over
+            statesave(&arg.state, answers, records, guesses, results, None)?;
+            for keyboard in keyboards.iter_mut() {
+                *keyboard = history
+                    .keys
+                    .iter()
+                    .map(|row| vec![None; row.len()])
+                    .collect();
+            }
+            let append = stats(guesses, results);
+            information.result = result(&append, information.lang);
+            information.distribution = append.bars();
             let mut text = String::new();
-            for i in 0..preference.len() {
-                text += &(format!("{}: {}; ", preference[i].0, preference[i].1));
+            for (word, times) in append.words.iter() {
+                text += &(format!("{}: {}; ", word, times));
             }
             information.preference = text;
+            if sharing.auto {
+                let shared = share_round(history, results, level, sharing, information.lang);
+                information.message = format!("{} {}", information.message, shared);
+            }
             loop {
-                terminal.draw(|f| ui(f, history, input, information, keyboard))?;
+                terminal.draw(|f| ui(f, history, input, information, keyboards))?;
                 if crossterm::event::poll(Duration::from_secs(1))? {
                     if let Event::Key(key) = event::read()? {
                         match key.code {
                             KeyCode::Char('y') => {
-                                sub = (sub + 1) % unsafe { FINALSET.len() };
                                 *history = History {
                                     guesses: vec![],
                                     colors: vec![],
                                     buf: vec![],
+                                    length: history.length,
+                                    limit: history.limit,
+                                    solved: vec![None; history.solved.len()],
+                                    keys: history.keys.clone(),
+                                    start: Instant::now(),
+                                    time: None,
+                                    record: Details::default(), // set when the round starts
                                 };
                                 information.message = String::from("");
                                 information.result = String::from("");
                                 information.preference = String::from("");
+                                information.distribution = vec![];
                                 break; // reinitialization
                             }
                             KeyCode::Esc => {
                                 exit = true;
                                 break;
                             }
-                            _ => {
+                            KeyCode::Char('s') => {
                                 information.message =
-                                    String::from("Input 'Y' for another game, click 'ESC' to exit");
+                                    share_round(history, results, level, sharing, information.lang);
+                            }
+                            _ => {
+                                information.message = information.lang.text(Msg::AnotherOrShare);
                             }
                         }
                     }
@@ -425,21 +829,17 @@ input: f: the terminal
         history: the inputs and current state of a round of game
         inputs: last keycode
         information: the message and statistic for the player
-        keyboard: the current state of colors of keyboard
+        key_boards: the current state of colors of the keyboard of each board
 output: None
 */
+#[allow(clippy::needless_range_loop)]
 fn ui<B: Backend>(
     f: &mut Frame<B>,
     history: &History,
     input: &Option<char>,
     information: &Information,
-    key_board: &Vec<Vec<char>>,
+    key_boards: &[Vec<Vec<Option<Tile>>>],
 ) {
-    let kboard = vec![
-        vec!['Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P'],
-        vec!['A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L'],
-        vec!['Z', 'X', 'C', 'V', 'B', 'N', 'M'],
-    ];
     let all_chunks = Layout::default()
         .constraints([Constraint::Percentage(67), Constraint::Percentage(33)].as_ref())
         .direction(Direction::Vertical)
@@ -457,7 +857,9 @@ fn ui<B: Backend>(
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::White))
             .border_type(BorderType::Rounded)
-            .title("History"),
+            .title(information.lang.text(Msg::HistoryTitle(&clock(
+                history.time.unwrap_or_else(|| seconds(history.start)),
+            )))), // the clock stops when the round is over
     )
     .alignment(tui::layout::Alignment::Left);
     f.render_widget(paragraph, up_chunks[0]); // upleft history
@@ -468,7 +870,7 @@ fn ui<B: Backend>(
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title("information")
+            .title(information.lang.text(Msg::InformationTitle))
             .title_alignment(tui::layout::Alignment::Center),
     )
     .alignment(tui::layout::Alignment::Left);
@@ -480,17 +882,28 @@ fn ui<B: Backend>(
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::White))
                 .border_type(BorderType::Rounded)
-                .title("Keyboard")
+                .title(information.lang.text(Msg::KeyboardTitle))
                 .title_alignment(tui::layout::Alignment::Center),
         );
     f.render_widget(paragraph, all_chunks[1]); // down keynoard
+    let over = used_up(history.guesses.len(), history.limit);
+    let rows = if history.limit == UNLIMITED || history.limit > ROWS {
+        ROWS
+    } else {
+        history.limit
+    };
+    let first = if over {
+        history.guesses.len().saturating_sub(rows)
+    } else {
+        (history.guesses.len() + 1).saturating_sub(rows)
+    }; // the earlier guesses scroll away
     let words = cut(up_chunks[0], 4, Direction::Vertical);
     let words = cut(words, 5, Direction::Horizontal);
     let mut words = Layout::default()
-        .constraints([Constraint::Percentage(16); 7].as_ref())
+        .constraints(vec![Constraint::Percentage(100 / rows as u16); rows + 1])
         .direction(Direction::Vertical)
-        .split(words); // split history into 6 lines, each for a guess
-    for i in 0..LIMIT {
+        .split(words); // split history into lines, each for a guess
+    for i in 0..rows {
         let paragraph = Paragraph::new(Span::styled(
             "",
             Style::default().add_modifier(Modifier::BOLD),
@@ -498,7 +911,7 @@ fn ui<B: Backend>(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("guess ".to_string() + &(i + 1).to_string())
+                .title(information.lang.text(Msg::GuessTitle(first + i + 1)))
                 .title_alignment(tui::layout::Alignment::Center),
         )
         .alignment(tui::layout::Alignment::Center);
@@ -521,96 +934,120 @@ fn ui<B: Backend>(
     let informations = cut(up_chunks[1], 5, Direction::Vertical);
     let informations = cut(informations, 5, Direction::Horizontal);
     let informations = Layout::default()
-        .constraints([Constraint::Percentage(25); 5].as_ref())
+        .constraints([Constraint::Percentage(20); 5].as_ref())
         .direction(Direction::Vertical)
-        .split(informations); // split information into 4 lines
+        .split(informations); // split information into 5 lines
     let keyboard = cut(all_chunks[1], 10, Direction::Vertical);
     let keyboard = cut(keyboard, 5, Direction::Horizontal);
-    let keyboard = Layout::default()
-        .constraints([Constraint::Percentage(33); 4].as_ref())
-        .vertical_margin(1)
-        .direction(Direction::Vertical)
-        .split(keyboard); // split keyboard into 6 lines
+    let letter_constraints =
+        vec![Constraint::Percentage(100 / history.length as u16); history.length + 1]; // a tile for each letter
+    let boards = history.solved.len();
+    let board_constraints = vec![Constraint::Ratio(1, boards as u32); boards]; // the boards side by side
 
-    for i in 0..history.guesses.len() {
-        let mut letters = Layout::default()
-            .constraints([Constraint::Percentage(20); 6].as_ref())
+    for i in first..history.guesses.len() {
+        let segments = Layout::default()
+            .constraints(board_constraints.clone())
             .direction(Direction::Horizontal)
-            .split(words[i]);
-        for j in 0..LENGTH {
-            letters[j] = cut(letters[j], 5, Direction::Horizontal);
-            let paragraph = Paragraph::new(Span::styled(
-                history.guesses[i][j].to_string(),
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .block(Block::default().style(Style::default().bg(char2color(history.colors[i][j]))))
-            .alignment(Alignment::Center);
-            f.render_widget(paragraph, letters[j]);
+            .split(words[i - first]);
+        for (b, color) in history.colors[i].iter().enumerate() {
+            let color = match color {
+                Some(color) => color,
+                None => continue, // the board was solved before, left blank
+            };
+            let mut letters = Layout::default()
+                .constraints(letter_constraints.clone())
+                .direction(Direction::Horizontal)
+                .split(segments[b]);
+            for j in 0..history.length {
+                letters[j] = cut(letters[j], 5, Direction::Horizontal);
+                let paragraph = Paragraph::new(Span::styled(
+                    history.guesses[i][j].to_string(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .block(Block::default().style(Style::default().bg(tile2color(Some(color.tile(j))))))
+                .alignment(Alignment::Center);
+                f.render_widget(paragraph, letters[j]);
+            }
         }
     } // draw history
-    if history.guesses.len() < LIMIT {
+    if !over {
         // if game not over
-        let mut letters = Layout::default()
-            .constraints([Constraint::Percentage(20); 6].as_ref())
+        let segments = Layout::default()
+            .constraints(board_constraints.clone())
             .direction(Direction::Horizontal)
-            .split(words[history.guesses.len()]);
+            .split(words[history.guesses.len() - first]);
+        for b in 0..boards {
+            if history.solved[b].is_some() {
+                continue; // nothing more to guess on this board
+            }
+            let mut letters = Layout::default()
+                .constraints(letter_constraints.clone())
+                .direction(Direction::Horizontal)
+                .split(segments[b]);
 
-        for j in 0..history.buf.len() {
-            letters[j] = cut(letters[j], 5, Direction::Horizontal);
-            let paragraph = Paragraph::new(Span::styled(
-                history.buf[j].to_string(),
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .block(Block::default().style(Style::default().bg(Color::Gray)))
-            .alignment(Alignment::Center);
-            f.render_widget(paragraph, letters[j]);
-        } // draw buff
+            for j in 0..history.buf.len() {
+                letters[j] = cut(letters[j], 5, Direction::Horizontal);
+                let paragraph = Paragraph::new(Span::styled(
+                    history.buf[j].to_string(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .block(Block::default().style(Style::default().bg(Color::Gray)))
+                .alignment(Alignment::Center);
+                f.render_widget(paragraph, letters[j]);
+            } // draw buff
 
-        if input.is_some() {
-            // draw input, if input is not the first letter of a guess
-            let j = history.buf.len();
-            letters[j] = cut(letters[j], 5, Direction::Horizontal);
-            let paragraph = Paragraph::new(Span::styled(
-                input.unwrap().to_string(),
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .block(
-                Block::default()
-                    .borders(Borders::BOTTOM)
-                    .border_style(Style::default().fg(Color::Blue))
-                    .border_type(BorderType::Rounded)
-                    .style(Style::default().bg(Color::White)),
-            )
-            .alignment(Alignment::Center); // blue underline
-            f.render_widget(paragraph, letters[j]);
-        } else {
-            // draw input, if input is the first letter of a guess
-            let j = history.buf.len();
-            letters[j] = cut(letters[j], 5, Direction::Horizontal);
-            let paragraph = Paragraph::new(Span::styled(
-                "",
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .block(Block::default().style(Style::default().bg(Color::White)))
-            .alignment(Alignment::Center);
-            f.render_widget(paragraph, letters[j]);
-        }
+            if input.is_some() {
+                // draw input, if input is not the first letter of a guess
+                let j = history.buf.len();
+                letters[j] = cut(letters[j], 5, Direction::Horizontal);
+                let paragraph = Paragraph::new(Span::styled(
+                    input.unwrap().to_string(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .block(
+                    Block::default()
+                        .borders(Borders::BOTTOM)
+                        .border_style(Style::default().fg(Color::Blue))
+                        .border_type(BorderType::Rounded)
+                        .style(Style::default().bg(Color::White)),
+                )
+                .alignment(Alignment::Center); // blue underline
+                f.render_widget(paragraph, letters[j]);
+            } else {
+                // draw input, if input is the first letter of a guess
+                let j = history.buf.len();
+                letters[j] = cut(letters[j], 5, Direction::Horizontal);
+                let paragraph = Paragraph::new(Span::styled(
+                    "",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .block(Block::default().style(Style::default().bg(Color::White)))
+                .alignment(Alignment::Center);
+                f.render_widget(paragraph, letters[j]);
+            }
 
-        for j in history.buf.len() + 1..LENGTH {
-            // draw after the input
-            letters[j] = cut(letters[j], 5, Direction::Horizontal);
-            let paragraph = Paragraph::new(Span::styled(
-                "",
-                Style::default().add_modifier(Modifier::BOLD),
-            ))
-            .block(Block::default().style(Style::default().bg(Color::Gray)))
-            .alignment(Alignment::Center);
-            f.render_widget(paragraph, letters[j]);
+            for j in history.buf.len() + 1..history.length {
+                // draw after the input
+                letters[j] = cut(letters[j], 5, Direction::Horizontal);
+                let paragraph = Paragraph::new(Span::styled(
+                    "",
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .block(Block::default().style(Style::default().bg(Color::Gray)))
+                .alignment(Alignment::Center);
+                f.render_widget(paragraph, letters[j]);
+            }
         }
     }
 
-    let title = vec!["message", "mode", "statistic", "words preferred"];
-    let text = vec![
+    let title = [
+        Msg::MessageTitle,
+        Msg::ModeTitle,
+        Msg::StatisticTitle,
+        Msg::WordsTitle,
+    ]
+    .map(|title| information.lang.text(title));
+    let text = [
         information.message.clone(),
         information.mode.clone(),
         information.result.clone(),
@@ -624,79 +1061,78 @@ fn ui<B: Backend>(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(title[i])
+                .title(title[i].as_str())
                 .title_alignment(tui::layout::Alignment::Center),
         )
         .alignment(tui::layout::Alignment::Center);
         f.render_widget(paragraph, informations[i]);
     } // draw the information
-
-    let mut bordercoler = key_board.clone();
-    if input.is_some() {
-        let (h, v) = char2location(input.unwrap());
-        bordercoler[h][v] = 'B';
-    } // input not the first letter of a guess, the selected letter on keyboard bordered blue
-
-    let mut line = Layout::default()
-        .constraints([Constraint::Percentage(10); 11].as_ref())
-        .direction(Direction::Horizontal)
-        .split(keyboard[0]);
-    for i in 0..10 {
-        line[i] = cut(line[i], 5, Direction::Horizontal);
-        let paragraph = Paragraph::new(Span::styled(
-            (kboard[0][i]).to_string(),
-            Style::default().add_modifier(Modifier::BOLD),
-        ))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(char2border(bordercoler[0][i])))
-                .border_type(BorderType::Rounded)
-                .style(Style::default().bg(char2color(key_board[0][i]))),
-        )
-        .alignment(Alignment::Center);
-        f.render_widget(paragraph, line[i]);
-    } // draw the first line of the keyboard
-    let mut line = Layout::default()
-        .constraints([Constraint::Percentage(11); 10].as_ref())
-        .horizontal_margin(4)
-        .direction(Direction::Horizontal)
-        .split(keyboard[1]);
-    for i in 0..9 {
-        line[i] = cut(line[i], 5, Direction::Horizontal);
-        let paragraph = Paragraph::new(Span::styled(
-            (kboard[1][i]).to_string(),
-            Style::default().add_modifier(Modifier::BOLD),
-        ))
+    let bars: Vec<(&str, u64)> = information
+        .distribution
+        .iter()
+        .map(|(label, count)| (label.as_str(), *count as u64))
+        .collect();
+    let chart = BarChart::default()
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(char2border(bordercoler[1][i])))
-                .border_type(BorderType::Rounded)
-                .style(Style::default().bg(char2color(key_board[1][i]))),
+                .title(information.lang.text(Msg::DistributionTitle))
+                .title_alignment(tui::layout::Alignment::Center),
         )
-        .alignment(Alignment::Center);
-        f.render_widget(paragraph, line[i]);
-    } // the second line
-    let mut line = Layout::default()
-        .constraints([Constraint::Percentage(12); 8].as_ref())
-        .horizontal_margin(10)
+        .data(&bars)
+        .bar_width(3)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(Color::Green))
+        .value_style(Style::default().fg(Color::Black).bg(Color::Green));
+    f.render_widget(chart, informations[4]); // empty until a round is over
+
+    let mut selected: Vec<Vec<bool>> = history
+        .keys
+        .iter()
+        .map(|row| vec![false; row.len()])
+        .collect();
+    if let Some((h, v)) = input.and_then(|letter| char2location(letter, &history.keys)) {
+        selected[h][v] = true;
+    } // input not the first letter of a guess, the selected letter on keyboard bordered blue
+
+    let widest = history.keys.iter().map(|row| row.len()).max().unwrap_or(1) as u32;
+    let sides = Layout::default()
+        .constraints(vec![Constraint::Ratio(1, boards as u32); boards])
         .direction(Direction::Horizontal)
-        .split(keyboard[2]);
-    for i in 0..7 {
-        line[i] = cut(line[i], 5, Direction::Horizontal);
-        let paragraph = Paragraph::new(Span::styled(
-            (kboard[2][i]).to_string(),
-            Style::default().add_modifier(Modifier::BOLD),
-        ))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(char2border(bordercoler[2][i])))
-                .border_type(BorderType::Rounded)
-                .style(Style::default().bg(char2color(key_board[2][i]))),
-        )
-        .alignment(Alignment::Center);
-        f.render_widget(paragraph, line[i]);
-    } // the third
-}
\ No newline at end of file
+        .split(keyboard); // the keyboards of the boards side by side
+    for (side, key_board) in sides.iter().zip(key_boards.iter()) {
+        let lines = Layout::default()
+            .constraints(vec![
+                Constraint::Ratio(1, history.keys.len() as u32);
+                history.keys.len() + 1
+            ])
+            .vertical_margin(1)
+            .direction(Direction::Vertical)
+            .split(*side); // split keyboard into a line for each row of keys
+        for (h, row) in history.keys.iter().enumerate() {
+            let margin = Constraint::Ratio(widest - row.len() as u32, 2 * widest);
+            let mut constraints = vec![margin];
+            constraints.extend(vec![Constraint::Ratio(1, widest); row.len()]);
+            constraints.push(margin); // the shorter rows are centered
+            let line = Layout::default()
+                .constraints(constraints)
+                .direction(Direction::Horizontal)
+                .split(lines[h]);
+            for (v, key) in row.iter().enumerate() {
+                let paragraph = Paragraph::new(Span::styled(
+                    key.to_string(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(select2border(selected[h][v])))
+                        .border_type(BorderType::Rounded)
+                        .style(Style::default().bg(tile2color(key_board[h][v]))),
+                )
+                .alignment(Alignment::Center);
+                f.render_widget(paragraph, cut(line[v + 1], 5, Direction::Horizontal));
+            }
+        } // draw the keyboard row by row
+    }
+}